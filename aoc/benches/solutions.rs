@@ -0,0 +1,190 @@
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use common::Solution;
+use criterion::Criterion;
+
+fn day_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{day}"))
+}
+
+/// the real input, if present, and all examples of a day
+fn inputs(day: u8) -> Vec<(String, String)> {
+    let dir = day_dir(day);
+    let mut inputs = Vec::new();
+    if let Ok(input) = std::fs::read_to_string(dir.join("input")) {
+        inputs.push(("input".to_owned(), input));
+    }
+    let mut examples: Vec<_> = std::fs::read_dir(dir.join("examples"))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    examples.sort();
+    for path in examples {
+        let name = match path.file_stem().unwrap().to_string_lossy() {
+            stem if stem == "example" => stem.into_owned(),
+            stem => format!("example-{stem}"),
+        };
+        let input = std::fs::read_to_string(&path).unwrap();
+        inputs.push((name, input));
+    }
+    inputs
+}
+
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8) {
+    for (name, input) in inputs(day) {
+        let mut group = c.benchmark_group(format!("day{day}/{name}"));
+        if name == "input" {
+            group.sample_size(10);
+        }
+        group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+        let parsed = S::parse(&input).unwrap();
+        group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+        group.finish();
+    }
+}
+
+fn solutions(c: &mut Criterion) {
+    bench_day::<day1::Day1>(c, 1);
+    bench_day::<day2::Day2>(c, 2);
+    bench_day::<day3::Day3>(c, 3);
+    bench_day::<day4::Day4>(c, 4);
+    bench_day::<day5::Day5>(c, 5);
+    bench_day::<day6::Day6>(c, 6);
+    bench_day::<day7::Day7>(c, 7);
+    bench_day::<day8::Day8>(c, 8);
+}
+
+fn main() {
+    let start = SystemTime::now();
+    let mut criterion = Criterion::default()
+        .output_directory(&baseline::criterion_dir())
+        .configure_from_args();
+    solutions(&mut criterion);
+    criterion.final_summary();
+    baseline::report(start);
+}
+
+/// The mean times of the last saved baseline are checked in as `baseline.json`, so that
+/// performance changes show up in review. Saving a baseline updates the file with the
+/// benchmarks that ran, any other run is compared against it.
+mod baseline {
+    use std::collections::BTreeMap;
+    use std::path::{Path, PathBuf};
+    use std::time::SystemTime;
+
+    use serde_json::Value;
+
+    /// Same as criterion's default, but without asking cargo for the target directory.
+    pub fn criterion_dir() -> PathBuf {
+        if let Some(dir) = std::env::var_os("CRITERION_HOME") {
+            return dir.into();
+        }
+        let target = match std::env::var_os("CARGO_TARGET_DIR") {
+            Some(dir) => PathBuf::from(dir),
+            None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../target"),
+        };
+        target.join("criterion")
+    }
+
+    fn path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("benches/baseline.json")
+    }
+
+    pub fn report(start: SystemTime) {
+        let saved = saved_baseline();
+        let means = measured(saved.as_deref().unwrap_or("new"), start);
+        if means.is_empty() {
+            return;
+        }
+        let mut baseline = load();
+        if saved.is_some() {
+            baseline.extend(means);
+            let json = serde_json::to_string_pretty(&baseline).unwrap();
+            std::fs::write(path(), json + "\n").unwrap();
+            println!("Saved the mean times to {}", path().display());
+            return;
+        }
+        println!(
+            "\n{:<32} {:>14} {:>14} {:>8}",
+            "benchmark", "baseline", "now", "change"
+        );
+        for (id, mean) in means {
+            let Some(&base) = baseline.get(&id) else {
+                println!("{id:<32} {:>14} {:>14} {:>8}", "-", ns(mean), "new");
+                continue;
+            };
+            let change = (mean as f64 / base as f64 - 1.0) * 100.0;
+            println!("{id:<32} {:>14} {:>14} {change:>+7.1}%", ns(base), ns(mean));
+        }
+    }
+
+    fn ns(mean: u64) -> String {
+        format!("{mean} ns")
+    }
+
+    /// The name given to `--save-baseline`.
+    fn saved_baseline() -> Option<String> {
+        let mut args = std::env::args();
+        while let Some(arg) = args.next() {
+            if arg == "--save-baseline" {
+                return args.next();
+            }
+            if let Some(name) = arg.strip_prefix("--save-baseline=") {
+                return Some(name.to_owned());
+            }
+        }
+        None
+    }
+
+    fn load() -> BTreeMap<String, u64> {
+        std::fs::read_to_string(path())
+            .map(|json| serde_json::from_str(&json).unwrap())
+            .unwrap_or_default()
+    }
+
+    /// Mean times in ns of the benchmarks that criterion stored under `name` since `start`.
+    fn measured(name: &str, start: SystemTime) -> BTreeMap<String, u64> {
+        let mut means = BTreeMap::new();
+        for group in subdirs(&criterion_dir()) {
+            for benchmark in subdirs(&group) {
+                let dir = benchmark.join(name);
+                let estimates = dir.join("estimates.json");
+                let fresh = std::fs::metadata(&estimates)
+                    .and_then(|meta| meta.modified())
+                    .is_ok_and(|modified| modified >= start);
+                if !fresh {
+                    continue;
+                }
+                let id = read_json(&dir.join("benchmark.json"))
+                    .and_then(|benchmark| benchmark["full_id"].as_str().map(str::to_owned));
+                let mean = read_json(&estimates)
+                    .and_then(|estimates| estimates["mean"]["point_estimate"].as_f64());
+                if let (Some(id), Some(mean)) = (id, mean) {
+                    means.insert(id, mean.round() as u64);
+                }
+            }
+        }
+        means
+    }
+
+    fn subdirs(dir: &Path) -> impl Iterator<Item = PathBuf> {
+        std::fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+    }
+
+    fn read_json(path: &Path) -> Option<Value> {
+        serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()
+    }
+}
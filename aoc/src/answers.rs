@@ -0,0 +1,189 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use common::Answer;
+
+pub const DEFAULT_INPUT_ID: &str = "default";
+
+/// An input id names a file in `dayN/inputs` and is one field of an answers line, so it
+/// must not leave that directory or contain whitespace.
+pub fn parse_input_id(id: &str) -> Result<String, String> {
+    if id.is_empty() {
+        return Err("the input id is empty".to_owned());
+    }
+    if id.contains(['/', '\\']) || id.contains("..") || id.contains(char::is_whitespace) {
+        return Err(format!(
+            "invalid input id '{id}', it must not contain '/', '\\', '..' or whitespace"
+        ));
+    }
+    Ok(id.to_owned())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub day: u8,
+    pub part: u8,
+    pub input_id: String,
+}
+
+/// Registry of known answers, keyed by day, part and input id.
+#[derive(Debug, Default, Clone)]
+pub struct Answers(BTreeMap<Key, Answer>);
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let content = std::fs::read_to_string(path).map_err(|err| AnswersError {
+            line: 0,
+            message: format!("cannot read '{}': {err}", path.display()),
+        })?;
+        Self::parse(&content)
+    }
+
+    pub fn parse(content: &str) -> Result<Self, AnswersError> {
+        let mut answers = BTreeMap::new();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: &str| AnswersError {
+                line: i + 1,
+                message: format!("{message}: '{line}'"),
+            };
+            let fields: Vec<_> = line.split_whitespace().collect();
+            let [day, part, input_id, answer] = fields[..] else {
+                return Err(error("expected '<day> <part> <input id> <answer>'"));
+            };
+            let key = Key {
+                day: day.parse().map_err(|_| error("invalid day"))?,
+                part: part.parse().map_err(|_| error("invalid part"))?,
+                input_id: parse_input_id(input_id).map_err(|_| error("invalid input id"))?,
+            };
+            let answer = answer.parse().map_err(|_| error("invalid answer"))?;
+            if answers.insert(key, answer).is_some() {
+                return Err(error("duplicate answer"));
+            }
+        }
+        Ok(Self(answers))
+    }
+
+    /// Appends a newly found answer to the answers file, on a line of its own even if the
+    /// file does not end with a newline.
+    pub fn append(path: &Path, key: &Key, answer: Answer) -> std::io::Result<()> {
+        use std::io::{Read, Seek, SeekFrom, Write};
+
+        let mut file = std::fs::OpenOptions::new()
+            .read(true)
+            .append(true)
+            .open(path)?;
+        if file.metadata()?.len() > 0 {
+            let mut last = [0];
+            file.seek(SeekFrom::End(-1))?;
+            file.read_exact(&mut last)?;
+            if last != *b"\n" {
+                writeln!(file)?;
+            }
+        }
+        writeln!(file, "{} {} {} {answer}", key.day, key.part, key.input_id)
+    }
+
+    pub fn get(&self, day: u8, part: u8, input_id: &str) -> Option<Answer> {
+        let key = Key {
+            day,
+            part,
+            input_id: input_id.to_owned(),
+        };
+        self.0.get(&key).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, Answer)> {
+        self.0.iter().map(|(key, answer)| (key, *answer))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnswersError {
+    line: usize,
+    message: String,
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            0 => write!(f, "{}", self.message),
+            line => write!(f, "answers line {line}: {}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let content = "\
+            # comment\n\
+            1 1 default 2086478\n\
+            \n\
+            7 1 other 8401132154762\n\
+        ";
+        let answers = Answers::parse(content).unwrap();
+        assert_eq!(answers.get(1, 1, "default"), Some(2086478_i64.into()));
+        assert_eq!(answers.get(7, 1, "other"), Some(8401132154762_u64.into()));
+        assert_eq!(answers.get(7, 1, "default"), None);
+        assert_eq!(answers.iter().count(), 2);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Answers::parse("1 1 default").unwrap_err();
+        assert_eq!(err.line, 1);
+        let err = Answers::parse("1 1 default 1\n1 x default 1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "answers line 2: invalid part: '1 x default 1'"
+        );
+        let err = Answers::parse("1 1 default 1\n1 1 default 2").unwrap_err();
+        assert_eq!(err.line, 2);
+        let err = Answers::parse("1 1 ../x 1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "answers line 1: invalid input id: '1 1 ../x 1'"
+        );
+    }
+
+    #[test]
+    fn test_parse_input_id() {
+        assert_eq!(parse_input_id("other-1.txt"), Ok("other-1.txt".to_owned()));
+        for id in ["", "../../x", "a/b", "a\\b", "..", "a b", "a\tb"] {
+            assert!(parse_input_id(id).is_err(), "{id:?}");
+        }
+        assert_eq!(
+            parse_input_id("a/b").unwrap_err(),
+            "invalid input id 'a/b', it must not contain '/', '\\', '..' or whitespace"
+        );
+    }
+
+    #[test]
+    fn test_append() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        let key = |day| Key {
+            day,
+            part: 1,
+            input_id: DEFAULT_INPUT_ID.to_owned(),
+        };
+        std::fs::write(&path, "").unwrap();
+        Answers::append(&path, &key(1), 11_i64.into()).unwrap();
+        std::fs::write(&path, std::fs::read_to_string(&path).unwrap().trim_end()).unwrap();
+        Answers::append(&path, &key(2), 22_i64.into()).unwrap();
+        Answers::append(&path, &key(3), 33_i64.into()).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(content, "1 1 default 11\n2 1 default 22\n3 1 default 33\n");
+        assert_eq!(Answers::parse(&content).unwrap().iter().count(), 3);
+    }
+}
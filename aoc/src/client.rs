@@ -0,0 +1,138 @@
+use std::fmt;
+use std::io;
+
+use common::Answer;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2024;
+
+const USER_AGENT: &str = "github.com/pantos9000/advent-of-code-2024";
+
+/// Talks to the puzzle website, authenticated by the session cookie.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::AgentBuilder::new().user_agent(USER_AGENT).build();
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+        }
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!("{}/{YEAR}/day/{day}", self.base_url)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        let url = format!("{}/input", self.day_url(day));
+        let response = self.agent.get(&url).set("Cookie", &self.cookie()).call()?;
+        Ok(response.into_string()?)
+    }
+
+    /// Posts an answer and returns the page the server responded with.
+    pub fn answer(&self, day: u8, part: u8, answer: Answer) -> Result<String, ClientError> {
+        let url = format!("{}/answer", self.day_url(day));
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[
+                ("level", &part.to_string()),
+                ("answer", &answer.to_string()),
+            ])?;
+        Ok(response.into_string()?)
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// the server answered with an error status
+    Status(u16, String),
+    Transport(String),
+    Io(io::Error),
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Status(code, response) => {
+                Self::Status(code, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(transport) => Self::Transport(transport.to_string()),
+        }
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Status(code, body) => write!(f, "server responded with {code}: {}", body.trim()),
+            Self::Transport(err) => write!(f, "request failed: {err}"),
+            Self::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake_server::FakeServer;
+
+    #[test]
+    fn test_input() {
+        let server = FakeServer::start(|_| (200, "1 2\n3 4\n".to_owned()));
+        let client = Client::new(&server.url(), "secret");
+        assert_eq!(client.input(3).unwrap(), "1 2\n3 4\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2024/day/3/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+    }
+
+    #[test]
+    fn test_answer() {
+        let server = FakeServer::start(|_| {
+            (
+                200,
+                "<article>That's the right answer!</article>".to_owned(),
+            )
+        });
+        let client = Client::new(&server.url(), "secret");
+        let page = client.answer(7, 2, 11387_i64.into()).unwrap();
+        assert!(page.contains("right answer"));
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2024/day/7/answer");
+        assert_eq!(requests[0].body, "level=2&answer=11387");
+    }
+
+    #[test]
+    fn test_error_status() {
+        let server = FakeServer::start(|_| (400, "Please log in.".to_owned()));
+        let client = Client::new(&server.url(), "invalid");
+        let err = client.input(1).unwrap_err();
+        assert!(matches!(err, ClientError::Status(400, _)));
+        assert_eq!(err.to_string(), "server responded with 400: Please log in.");
+    }
+}
@@ -0,0 +1,91 @@
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use common::{Answer, ParseError, Solution};
+use day1::stream::{Counts, StreamError};
+
+use crate::answers::DEFAULT_INPUT_ID;
+
+pub const DAYS: u8 = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+pub fn run(day: u8, part: u8, input: &str) -> Result<Run, ParseError> {
+    match day {
+        1 => run_solution::<day1::Day1>(part, input),
+        2 => run_solution::<day2::Day2>(part, input),
+        3 => run_solution::<day3::Day3>(part, input),
+        4 => run_solution::<day4::Day4>(part, input),
+        5 => run_solution::<day5::Day5>(part, input),
+        6 => run_solution::<day6::Day6>(part, input),
+        7 => run_solution::<day7::Day7>(part, input),
+        8 => run_solution::<day8::Day8>(part, input),
+        _ => panic!("no solution for day {day}"),
+    }
+}
+
+fn run_solution<S: Solution>(part: u8, input: &str) -> Result<Run, ParseError> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => S::part1(&input),
+        2 => S::part2(&input),
+        _ => panic!("no solution for part {part}"),
+    };
+    let solve_time = start.elapsed();
+
+    Ok(Run {
+        answer,
+        parse_time,
+        solve_time,
+    })
+}
+
+/// Days that can solve their input as it is read, without holding all of it in memory.
+pub const STREAMING_DAYS: [u8; 1] = [1];
+
+/// Reading and counting is the parse time, pairing up the counts the solve time.
+pub fn run_stream(day: u8, part: u8, reader: impl BufRead) -> Result<Run, StreamError> {
+    let start = Instant::now();
+    let counts = match day {
+        1 => Counts::read(reader)?,
+        _ => panic!("no streaming solution for day {day}"),
+    };
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => counts.distance(),
+        2 => counts.similarity(),
+        _ => panic!("no solution for part {part}"),
+    }
+    .into();
+    let solve_time = start.elapsed();
+
+    Ok(Run {
+        answer,
+        parse_time,
+        solve_time,
+    })
+}
+
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+pub fn input_path(day: u8, input_id: &str) -> PathBuf {
+    let day_dir = workspace_dir().join(format!("day{day}"));
+    match input_id {
+        DEFAULT_INPUT_ID => day_dir.join("input"),
+        input_id => day_dir.join("inputs").join(input_id),
+    }
+}
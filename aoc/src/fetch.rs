@@ -0,0 +1,54 @@
+use std::path::Path;
+
+use crate::client::{Client, ClientError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    /// the input was already cached, nothing was downloaded
+    Cached,
+}
+
+/// Downloads the input of a day into `path`, unless that file already exists.
+pub fn fetch(client: &Client, day: u8, path: &Path) -> Result<Fetched, ClientError> {
+    if path.exists() {
+        return Ok(Fetched::Cached);
+    }
+    let input = client.input(day)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, input)?;
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake_server::FakeServer;
+
+    #[test]
+    fn test_fetch_once() {
+        let server = FakeServer::start(|_| (200, "3   4\n".to_owned()));
+        let client = Client::new(&server.url(), "secret");
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let path = dir.join("day1").join("input");
+
+        assert_eq!(fetch(&client, 1, &path).unwrap(), Fetched::Downloaded);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "3   4\n");
+        assert_eq!(fetch(&client, 1, &path).unwrap(), Fetched::Cached);
+        assert_eq!(server.requests().len(), 1);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_error_writes_nothing() {
+        let server = FakeServer::start(|_| (404, "Not Found".to_owned()));
+        let client = Client::new(&server.url(), "secret");
+        let path = std::env::temp_dir().join(format!("aoc-fetch-missing-{}", std::process::id()));
+
+        assert!(fetch(&client, 26, &path).is_err());
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,349 @@
+mod answers;
+mod client;
+mod days;
+#[cfg(test)]
+mod fake_server;
+mod fetch;
+mod submit;
+mod verify;
+
+use std::error::Error;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use common::input::InputSource;
+use day1::stream::StreamError;
+
+#[derive(Parser)]
+#[command(about = "Run the Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution for one part of a day
+    Run {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Input file, `-` for stdin [default: $AOC_INPUT or the day's `input` file]
+        input: Option<String>,
+        /// Run on a known input and verify the answer
+        #[arg(long, conflicts_with = "input", value_parser = answers::parse_input_id)]
+        input_id: Option<String>,
+        /// Solve while reading the input instead of reading all of it first (day 1 only)
+        #[arg(long)]
+        stream: bool,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Run every solution and compare it with the recorded answers
+    Verify {
+        /// Answers file [default: `answers` in the workspace]
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Download the input of a day, unless it is already cached
+    Fetch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: u8,
+        /// Input id to store the input under
+        #[arg(
+            long,
+            default_value = answers::DEFAULT_INPUT_ID,
+            value_parser = answers::parse_input_id
+        )]
+        input_id: String,
+        #[command(flatten)]
+        client: ClientArgs,
+    },
+    /// Submit the answer of one part of a day, unless it is known to be wrong
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=days::DAYS as i64))]
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Input id to solve and submit the answer for
+        #[arg(
+            long,
+            default_value = answers::DEFAULT_INPUT_ID,
+            value_parser = answers::parse_input_id
+        )]
+        input_id: String,
+        #[command(flatten)]
+        client: ClientArgs,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Args)]
+struct ClientArgs {
+    /// Base URL of the puzzle website
+    #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+    /// Session cookie of the logged in user
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: String,
+}
+
+impl ClientArgs {
+    fn client(&self) -> client::Client {
+        client::Client::new(&self.base_url, &self.session)
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            input_id,
+            stream,
+            format,
+        } => {
+            if stream && !days::STREAMING_DAYS.contains(&day) {
+                eprintln!("day {day} cannot be solved while streaming its input");
+                return ExitCode::FAILURE;
+            }
+            let (source, input_id) = select_input(day, input, input_id);
+            let outcome = match run(day, part, &source, input_id.as_deref(), stream) {
+                Ok(outcome) => outcome,
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            match format {
+                Format::Text => print_outcome(&outcome),
+                Format::Json => println!("{}", outcome.to_json()),
+            }
+            exit_code(&[outcome])
+        }
+        Command::Verify { answers, format } => {
+            let answers = match load_answers(answers) {
+                Ok(answers) => answers,
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let outcomes = verify::verify(&answers);
+            match format {
+                Format::Text => verify::print_table(&outcomes),
+                Format::Json => {
+                    let json: Vec<_> = outcomes.iter().map(verify::Outcome::to_json).collect();
+                    println!("{}", serde_json::Value::Array(json));
+                }
+            }
+            exit_code(&outcomes)
+        }
+        Command::Fetch {
+            day,
+            input_id,
+            client,
+        } => {
+            let path = days::input_path(day, &input_id);
+            match fetch::fetch(&client.client(), day, &path) {
+                Ok(fetch::Fetched::Downloaded) => println!("Downloaded {}", path.display()),
+                Ok(fetch::Fetched::Cached) => println!("Already cached {}", path.display()),
+                Err(err) => {
+                    eprintln!("cannot fetch input of day {day}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            }
+            ExitCode::SUCCESS
+        }
+        Command::Submit {
+            day,
+            part,
+            input_id,
+            client,
+        } => {
+            let key = answers::Key {
+                day,
+                part,
+                input_id,
+            };
+            match submit_answer(&key, &client.client()) {
+                Ok(submit::Verdict::Right) => ExitCode::SUCCESS,
+                // wrong, or not judged at all
+                Ok(_) => ExitCode::FAILURE,
+                Err(err) => {
+                    eprintln!("{err}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+    }
+}
+
+fn load_answers(path: Option<PathBuf>) -> Result<answers::Answers, answers::AnswersError> {
+    let path = path.unwrap_or_else(|| days::workspace_dir().join("answers"));
+    answers::Answers::load(&path)
+}
+
+/// The input id is only known if the input is one of the known inputs.
+fn select_input(
+    day: u8,
+    input: Option<String>,
+    input_id: Option<String>,
+) -> (InputSource, Option<String>) {
+    if let Some(input_id) = input_id {
+        return (
+            InputSource::File(days::input_path(day, &input_id)),
+            Some(input_id),
+        );
+    }
+    let default_input = days::input_path(day, answers::DEFAULT_INPUT_ID);
+    let source = InputSource::select(input.as_deref(), default_input.clone());
+    let input_id =
+        (source == InputSource::File(default_input)).then(|| answers::DEFAULT_INPUT_ID.to_owned());
+    (source, input_id)
+}
+
+fn run(
+    day: u8,
+    part: u8,
+    source: &InputSource,
+    input_id: Option<&str>,
+    stream: bool,
+) -> Result<verify::Outcome, Box<dyn Error>> {
+    let answers = match input_id {
+        Some(_) => load_answers(None)?,
+        None => answers::Answers::default(),
+    };
+    if !stream {
+        let input = source.read()?;
+        return Ok(verify::Outcome::new(day, part, input_id, &input, &answers));
+    }
+    let run = match days::run_stream(day, part, source.open()?) {
+        Ok(run) => Ok(run),
+        Err(StreamError::Parse(err)) => Err(err),
+        Err(err @ StreamError::Io(_)) => return Err(format!("{source}: {err}").into()),
+    };
+    Ok(verify::Outcome::from_run(
+        day, part, input_id, run, &answers,
+    ))
+}
+
+fn print_outcome(outcome: &verify::Outcome) {
+    if let Some(answer) = outcome.answer() {
+        println!("Result is {answer}");
+    }
+    if let Some(err) = outcome.error() {
+        eprintln!("{err}");
+    }
+    if let (verify::Status::Fail, Some(expected)) = (outcome.status(), outcome.expected) {
+        eprintln!("Expected {expected}");
+    }
+}
+
+fn exit_code(outcomes: &[verify::Outcome]) -> ExitCode {
+    if outcomes.iter().any(|outcome| outcome.status().is_failure()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn submit_answer(
+    key: &answers::Key,
+    client: &client::Client,
+) -> Result<submit::Verdict, Box<dyn Error>> {
+    let input_path = days::input_path(key.day, &key.input_id);
+    let input = common::input::InputSource::File(input_path).read()?;
+    let answer = days::run(key.day, key.part, &input)?.answer;
+
+    let log_path = days::workspace_dir().join("submissions");
+    let mut submissions = submit::Submissions::load(&log_path)?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let result = submit::submit(client, &mut submissions, key, answer, now);
+    submissions.save(&log_path)?;
+    let response = result?;
+
+    println!("Answer {answer} is {}", response.verdict);
+    if let Some(wait) = response.wait {
+        println!("Wait {}s before the next submission", wait.as_secs());
+    }
+    if response.verdict == submit::Verdict::Right {
+        let answers_path = days::workspace_dir().join("answers");
+        let known = answers::Answers::load(&answers_path)?;
+        if known.get(key.day, key.part, &key.input_id).is_none() {
+            answers::Answers::append(&answers_path, key, answer)?;
+        }
+    }
+    Ok(response.verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dispatch() {
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        assert_eq!(days::run(1, 1, input).unwrap().answer, 11_i64.into());
+        assert_eq!(days::run(1, 2, input).unwrap().answer, 31_i64.into());
+        assert!(days::run(1, 1, "1 x").is_err());
+
+        let run = days::run_stream(1, 2, input.as_bytes()).unwrap();
+        assert_eq!(run.answer, 31_i64.into());
+        assert!(days::run_stream(1, 1, "1 x".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_cli() {
+        let cli = Cli::try_parse_from(["aoc", "run", "8", "2"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Run {
+                day: 8,
+                part: 2,
+                input: None,
+                input_id: None,
+                stream: false,
+                format: Format::Text,
+            }
+        ));
+        let cli = Cli::try_parse_from(["aoc", "run", "1", "2", "-", "--stream"]).unwrap();
+        assert!(matches!(cli.command, Command::Run { stream: true, .. }));
+        let cli = Cli::try_parse_from(["aoc", "run", "8", "2", "--format", "json"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Command::Run {
+                format: Format::Json,
+                ..
+            }
+        ));
+        assert!(Cli::try_parse_from(["aoc", "run", "1", "1", "-", "--input-id", "x"]).is_err());
+        for args in [
+            &["aoc", "run", "1", "1", "--input-id", "../../x"][..],
+            &["aoc", "fetch", "1", "--input-id", "a/b"],
+            &["aoc", "submit", "1", "1", "--input-id", ""],
+        ] {
+            assert!(Cli::try_parse_from(args).is_err(), "{args:?}");
+        }
+        let cli = Cli::try_parse_from(["aoc", "run", "1", "1", "-"]).unwrap();
+        assert!(matches!(cli.command, Command::Run { input: Some(i), .. } if i == "-"));
+        assert!(Cli::try_parse_from(["aoc", "run", "9", "1"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "1", "3"]).is_err());
+        let cli = Cli::try_parse_from(["aoc", "fetch", "8", "--session", "abc"]).unwrap();
+        assert!(matches!(cli.command, Command::Fetch { day: 8, .. }));
+        // there is no day crate to store the input in
+        assert!(Cli::try_parse_from(["aoc", "fetch", "9", "--session", "abc"]).is_err());
+    }
+}
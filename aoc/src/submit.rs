@@ -0,0 +1,393 @@
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+use common::Answer;
+
+use crate::answers::Key;
+use crate::client::{Client, ClientError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// the answer was not judged, because the last one was submitted too recently
+    TooRecent,
+    /// the part is already solved or not unlocked yet
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    fn is_judged(self) -> bool {
+        matches!(
+            self,
+            Self::Right | Self::Wrong | Self::TooHigh | Self::TooLow
+        )
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Right => "right",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too-high",
+            Self::TooLow => "too-low",
+            Self::TooRecent => "too-recent",
+            Self::WrongLevel => "wrong-level",
+            Self::Unknown => "unknown",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        let verdict = match s {
+            "right" => Self::Right,
+            "wrong" => Self::Wrong,
+            "too-high" => Self::TooHigh,
+            "too-low" => Self::TooLow,
+            _ => return None,
+        };
+        Some(verdict)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    /// how long the server wants us to wait before the next submission
+    pub wait: Option<Duration>,
+}
+
+impl Response {
+    pub fn parse(page: &str) -> Self {
+        let verdict = if page.contains("That's the right answer") {
+            Verdict::Right
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if page.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Verdict::TooRecent
+        } else if page.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        };
+        let wait = parse_time_left(page).or_else(|| parse_minutes_to_wait(page));
+        Self { verdict, wait }
+    }
+}
+
+/// parses "You have 1m 23s left to wait"
+fn parse_time_left(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let len = page[start..].find(" left to wait")?;
+    let mut seconds = 0;
+    for token in page[start..start + len].split_whitespace() {
+        let (unit_start, unit) = token.char_indices().last()?;
+        let num: u64 = token[..unit_start].parse().ok()?;
+        seconds += match unit {
+            'h' => num * 60 * 60,
+            'm' => num * 60,
+            's' => num,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// parses "please wait one minute before trying again" or "wait 5 minutes before ..."
+fn parse_minutes_to_wait(page: &str) -> Option<Duration> {
+    let start = page.find("wait ")? + "wait ".len();
+    let mut words = page[start..].split_whitespace();
+    let minutes = match words.next()? {
+        "one" => 1,
+        num => num.parse().ok()?,
+    };
+    if !words.next()?.starts_with("minute") {
+        return None;
+    }
+    Some(Duration::from_secs(minutes * 60))
+}
+
+#[derive(Debug, Clone)]
+struct Submission {
+    key: Key,
+    answer: Answer,
+    verdict: Verdict,
+}
+
+/// Log of judged submissions and of the time until the next submission is allowed.
+#[derive(Debug, Default, Clone)]
+pub struct Submissions {
+    submissions: Vec<Submission>,
+    /// unix time in seconds
+    wait_until: u64,
+}
+
+impl Submissions {
+    /// A missing file is an empty log.
+    pub fn load(path: &Path) -> Result<Self, SubmitError> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(SubmitError::Log(format!(
+                "cannot read '{}': {err}",
+                path.display()
+            ))),
+        }
+    }
+
+    fn parse(content: &str) -> Result<Self, SubmitError> {
+        let mut log = Self::default();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = || SubmitError::Log(format!("line {}: invalid entry '{line}'", i + 1));
+            let fields: Vec<_> = line.split_whitespace().collect();
+            match fields[..] {
+                ["wait", until] => log.wait_until = until.parse().map_err(|_| error())?,
+                [day, part, input_id, answer, verdict] => log.submissions.push(Submission {
+                    key: Key {
+                        day: day.parse().map_err(|_| error())?,
+                        part: part.parse().map_err(|_| error())?,
+                        input_id: input_id.to_owned(),
+                    },
+                    answer: answer.parse().map_err(|_| error())?,
+                    verdict: Verdict::parse(verdict).ok_or_else(error)?,
+                }),
+                _ => return Err(error()),
+            }
+        }
+        Ok(log)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), SubmitError> {
+        let mut content = String::from("# <day> <part> <input id> <answer> <verdict>\n");
+        for Submission {
+            key,
+            answer,
+            verdict,
+        } in &self.submissions
+        {
+            let Key {
+                day,
+                part,
+                input_id,
+            } = key;
+            content += &format!("{day} {part} {input_id} {answer} {verdict}\n");
+        }
+        if self.wait_until > 0 {
+            content += &format!("wait {}\n", self.wait_until);
+        }
+        std::fs::write(path, content)
+            .map_err(|err| SubmitError::Log(format!("cannot write '{}': {err}", path.display())))
+    }
+
+    /// Returns why the answer must not be submitted, if it is already known to be wrong.
+    fn check(&self, key: &Key, answer: Answer, now: u64) -> Result<(), SubmitError> {
+        let refuse = |reason: String| Err(SubmitError::Refused(reason));
+        if now < self.wait_until {
+            return refuse(format!(
+                "wait {}s before submitting again",
+                self.wait_until - now
+            ));
+        }
+        for submission in self.submissions.iter().filter(|s| &s.key == key) {
+            match submission.verdict {
+                Verdict::Right => {
+                    return refuse(format!("already solved with {}", submission.answer))
+                }
+                _ if submission.answer == answer => {
+                    return refuse(format!("already submitted as {}", submission.verdict))
+                }
+                Verdict::TooHigh if answer > submission.answer => {
+                    return refuse(format!("{} was already too high", submission.answer))
+                }
+                Verdict::TooLow if answer < submission.answer => {
+                    return refuse(format!("{} was already too low", submission.answer))
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    fn record(&mut self, key: &Key, answer: Answer, response: &Response, now: u64) {
+        if response.verdict.is_judged() {
+            self.submissions.push(Submission {
+                key: key.clone(),
+                answer,
+                verdict: response.verdict,
+            });
+        }
+        if let Some(wait) = response.wait {
+            self.wait_until = now + wait.as_secs();
+        }
+    }
+}
+
+/// Submits the answer, unless the log already tells that it is wrong, and records the verdict.
+pub fn submit(
+    client: &Client,
+    submissions: &mut Submissions,
+    key: &Key,
+    answer: Answer,
+    now: u64,
+) -> Result<Response, SubmitError> {
+    submissions.check(key, answer, now)?;
+    let page = client.answer(key.day, key.part, answer)?;
+    let response = Response::parse(&page);
+    submissions.record(key, answer, &response, now);
+    Ok(response)
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(String),
+    Client(ClientError),
+    Log(String),
+}
+
+impl From<ClientError> for SubmitError {
+    fn from(err: ClientError) -> Self {
+        Self::Client(err)
+    }
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Refused(reason) => write!(f, "refusing to submit: {reason}"),
+            Self::Client(err) => write!(f, "{err}"),
+            Self::Log(err) => write!(f, "submissions log: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake_server::FakeServer;
+
+    const RIGHT: &str =
+        "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        Please wait one minute before trying again.</p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again.  You have 1m 23s left to wait.</p></article>";
+
+    fn key() -> Key {
+        Key {
+            day: 1,
+            part: 1,
+            input_id: "default".to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_parse_time_left() {
+        let page = "You have 2h 1m 5s left to wait.";
+        assert_eq!(parse_time_left(page), Some(Duration::from_secs(7265)));
+        assert_eq!(parse_time_left("You have 1m 23秒 left to wait."), None);
+        assert_eq!(parse_time_left("You have 1m é left to wait."), None);
+        assert_eq!(parse_time_left("You have 5 left to wait."), None);
+    }
+
+    #[test]
+    fn test_parse_responses() {
+        let response = Response::parse(RIGHT);
+        assert_eq!(response.verdict, Verdict::Right);
+        assert_eq!(response.wait, None);
+
+        let response = Response::parse(TOO_HIGH);
+        assert_eq!(response.verdict, Verdict::TooHigh);
+        assert_eq!(response.wait, Some(Duration::from_secs(60)));
+
+        let response = Response::parse(TOO_RECENT);
+        assert_eq!(response.verdict, Verdict::TooRecent);
+        assert_eq!(response.wait, Some(Duration::from_secs(83)));
+
+        let page = "That's not the right answer; your answer is too low. \
+            Please wait 5 minutes before trying again.";
+        let response = Response::parse(page);
+        assert_eq!(response.verdict, Verdict::TooLow);
+        assert_eq!(response.wait, Some(Duration::from_secs(300)));
+
+        let page = "You don't seem to be solving the right level.  Did you already complete it?";
+        assert_eq!(Response::parse(page).verdict, Verdict::WrongLevel);
+        assert_eq!(Response::parse("<html></html>").verdict, Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_never_submit_twice() {
+        let server = FakeServer::start(|_| (200, TOO_HIGH.to_owned()));
+        let client = Client::new(&server.url(), "secret");
+        let mut log = Submissions::default();
+
+        let response = submit(&client, &mut log, &key(), 100.into(), 0).unwrap();
+        assert_eq!(response.verdict, Verdict::TooHigh);
+
+        let err = submit(&client, &mut log, &key(), 99.into(), 30).unwrap_err();
+        assert!(matches!(err, SubmitError::Refused(_)));
+        let err = submit(&client, &mut log, &key(), 100.into(), 60).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "refusing to submit: already submitted as too-high"
+        );
+        let err = submit(&client, &mut log, &key(), 101.into(), 60).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "refusing to submit: 100 was already too high"
+        );
+        assert_eq!(server.requests().len(), 1);
+
+        submit(&client, &mut log, &key(), 99.into(), 60).unwrap();
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_solved() {
+        let server = FakeServer::start(|_| (200, RIGHT.to_owned()));
+        let client = Client::new(&server.url(), "secret");
+        let mut log = Submissions::default();
+
+        submit(&client, &mut log, &key(), 42.into(), 0).unwrap();
+        let err = submit(&client, &mut log, &key(), 43.into(), 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "refusing to submit: already solved with 42"
+        );
+    }
+
+    #[test]
+    fn test_log_roundtrip() {
+        let mut log = Submissions::default();
+        log.record(&key(), 7.into(), &Response::parse(TOO_HIGH), 1000);
+        log.record(&key(), 3.into(), &Response::parse(TOO_RECENT), 1010);
+        let path = std::env::temp_dir().join(format!("aoc-submissions-{}", std::process::id()));
+        log.save(&path).unwrap();
+        let loaded = Submissions::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.submissions.len(), 1);
+        assert_eq!(loaded.submissions[0].verdict, Verdict::TooHigh);
+        assert_eq!(loaded.wait_until, 1093);
+        assert!(loaded.check(&key(), 5.into(), 1093).is_ok());
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::BTreeSet;
+use std::fmt;
+
+use common::{Answer, ParseError};
+
+use crate::answers::{Answers, DEFAULT_INPUT_ID};
+use crate::days::{self, Run};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// the input could not be parsed
+    Error,
+    /// no answer is recorded for this solution
+    Unknown,
+    /// an answer is recorded, but the input is missing
+    NoInput,
+}
+
+impl Status {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Fail => "fail",
+            Self::Error => "error",
+            Self::Unknown => "unknown",
+            Self::NoInput => "no-input",
+        }
+    }
+
+    pub fn is_failure(self) -> bool {
+        matches!(self, Self::Fail | Self::Error)
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Pass => "pass",
+            Self::Fail => "FAIL",
+            Self::Error => "ERROR",
+            Self::Unknown => "unknown",
+            Self::NoInput => "no input",
+        };
+        f.pad(s)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub day: u8,
+    pub part: u8,
+    /// `None` if the input is not one of the known inputs
+    pub input_id: Option<String>,
+    pub expected: Option<Answer>,
+    pub actual: Option<Result<Run, ParseError>>,
+}
+
+impl Outcome {
+    /// Runs the solution and looks up the answer it should have.
+    pub fn new(day: u8, part: u8, input_id: Option<&str>, input: &str, answers: &Answers) -> Self {
+        Self::from_run(day, part, input_id, days::run(day, part, input), answers)
+    }
+
+    /// Looks up the answer for a solution that has already run.
+    pub fn from_run(
+        day: u8,
+        part: u8,
+        input_id: Option<&str>,
+        run: Result<Run, ParseError>,
+        answers: &Answers,
+    ) -> Self {
+        Self {
+            day,
+            part,
+            input_id: input_id.map(str::to_owned),
+            expected: input_id.and_then(|input_id| answers.get(day, part, input_id)),
+            actual: Some(run),
+        }
+    }
+
+    pub fn answer(&self) -> Option<Answer> {
+        match &self.actual {
+            Some(Ok(run)) => Some(run.answer),
+            _ => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<&ParseError> {
+        match &self.actual {
+            Some(Err(err)) => Some(err),
+            _ => None,
+        }
+    }
+
+    pub fn status(&self) -> Status {
+        match (self.expected, &self.actual) {
+            (_, Some(Err(_))) => Status::Error,
+            (Some(_), None) => Status::NoInput,
+            (None, _) => Status::Unknown,
+            (Some(expected), Some(Ok(run))) if expected == run.answer => Status::Pass,
+            (Some(_), Some(Ok(_))) => Status::Fail,
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let run = match &self.actual {
+            Some(Ok(run)) => Some(run),
+            _ => None,
+        };
+        // answers are strings, so that big numbers don't lose precision in javascript
+        serde_json::json!({
+            "day": self.day,
+            "part": self.part,
+            "input_id": self.input_id,
+            "answer": self.answer().map(|answer| answer.to_string()),
+            "expected": self.expected.map(|answer| answer.to_string()),
+            "parse_time_ns": run.map(|run| run.parse_time.as_nanos() as u64),
+            "solve_time_ns": run.map(|run| run.solve_time.as_nanos() as u64),
+            "status": self.status().as_str(),
+            "error": self.error().map(|err| err.to_string()),
+        })
+    }
+}
+
+/// Runs every solution on the default input and on every input that has a recorded answer.
+pub fn verify(answers: &Answers) -> Vec<Outcome> {
+    let mut input_ids: BTreeSet<&str> = answers
+        .iter()
+        .map(|(key, _)| key.input_id.as_str())
+        .collect();
+    input_ids.insert(DEFAULT_INPUT_ID);
+
+    let mut outcomes = Vec::new();
+    for input_id in input_ids {
+        for day in 1..=days::DAYS {
+            let input = std::fs::read_to_string(days::input_path(day, input_id)).ok();
+            for part in 1..=2 {
+                let outcome = match &input {
+                    Some(input) => Outcome::new(day, part, Some(input_id), input, answers),
+                    None => Outcome {
+                        day,
+                        part,
+                        input_id: Some(input_id.to_owned()),
+                        expected: answers.get(day, part, input_id),
+                        actual: None,
+                    },
+                };
+                if outcome.actual.is_none() && outcome.expected.is_none() {
+                    continue;
+                }
+                outcomes.push(outcome);
+            }
+        }
+    }
+    outcomes
+}
+
+pub fn print_table(outcomes: &[Outcome]) {
+    let show = |answer: Option<Answer>| answer.map_or("-".to_owned(), |a| a.to_string());
+    println!(
+        "{:>3}  {:>4}  {:<10}  {:>16}  {:>16}  status",
+        "day", "part", "input", "expected", "actual"
+    );
+    for outcome in outcomes {
+        println!(
+            "{:>3}  {:>4}  {:<10}  {:>16}  {:>16}  {}",
+            outcome.day,
+            outcome.part,
+            outcome.input_id.as_deref().unwrap_or("-"),
+            show(outcome.expected),
+            show(outcome.answer()),
+            outcome.status(),
+        );
+        if let Some(err) = outcome.error() {
+            println!("     {err}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(expected: Option<i64>, actual: Option<i64>) -> Outcome {
+        Outcome {
+            day: 1,
+            part: 1,
+            input_id: Some(DEFAULT_INPUT_ID.to_owned()),
+            expected: expected.map(Answer::from),
+            actual: actual.map(|actual| {
+                Ok(Run {
+                    answer: actual.into(),
+                    parse_time: std::time::Duration::from_micros(3),
+                    solve_time: std::time::Duration::from_micros(5),
+                })
+            }),
+        }
+    }
+
+    #[test]
+    fn test_status() {
+        assert_eq!(outcome(Some(1), Some(1)).status(), Status::Pass);
+        assert_eq!(outcome(Some(1), Some(2)).status(), Status::Fail);
+        assert_eq!(outcome(None, Some(2)).status(), Status::Unknown);
+        assert_eq!(outcome(Some(1), None).status(), Status::NoInput);
+
+        let answers = Answers::default();
+        let failed = Outcome::new(1, 1, None, "1 x", &answers);
+        assert_eq!(failed.status(), Status::Error);
+    }
+
+    #[test]
+    fn test_json() {
+        let json = outcome(Some(1), Some(2)).to_json();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "day": 1,
+                "part": 1,
+                "input_id": "default",
+                "answer": "2",
+                "expected": "1",
+                "parse_time_ns": 3000,
+                "solve_time_ns": 5000,
+                "status": "fail",
+                "error": null,
+            })
+        );
+
+        let json = Outcome::new(1, 2, None, "1 x", &Answers::default()).to_json();
+        assert_eq!(json["status"], "error");
+        assert_eq!(json["answer"], serde_json::Value::Null);
+        assert_eq!(json["error"], "line 1, column 3: invalid number in 'x'");
+    }
+
+    /// slow in debug builds, `aoc verify` runs the same check
+    #[test]
+    #[ignore]
+    fn test_recorded_answers() {
+        let answers = Answers::load(&days::workspace_dir().join("answers")).unwrap();
+        for outcome in verify(&answers) {
+            assert_eq!(
+                outcome.status(),
+                Status::Pass,
+                "day {} part {} input {:?}",
+                outcome.day,
+                outcome.part,
+                outcome.input_id
+            );
+        }
+    }
+}
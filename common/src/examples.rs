@@ -0,0 +1,104 @@
+//! Puzzle examples stored as files next to the solutions.
+//!
+//! Every day keeps its examples in `examples/<name>.txt` and their expected answers in
+//! `examples/answers`, one `<part> <name> <answer>` per line. Lines starting with `#` are
+//! comments. [`example_tests!`](crate::example_tests) turns them into a test.
+
+use std::path::Path;
+
+use crate::Answer;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub part: u8,
+    pub input: String,
+    pub expected: Answer,
+}
+
+/// Loads all examples with an expected answer from `dir`.
+///
+/// This is meant for tests, so it panics on a malformed answers file, on an answer for a
+/// missing example and on an example without any answer.
+pub fn load(dir: &Path) -> Vec<Example> {
+    let answers_path = dir.join("answers");
+    let content = std::fs::read_to_string(&answers_path)
+        .unwrap_or_else(|err| panic!("cannot read '{}': {err}", answers_path.display()));
+
+    let mut examples = Vec::new();
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let error = |message: &str| -> ! {
+            panic!("{}:{}: {message}: '{line}'", answers_path.display(), i + 1)
+        };
+        let fields: Vec<_> = line.split_whitespace().collect();
+        let [part, name, answer] = fields[..] else {
+            error("expected '<part> <name> <answer>'");
+        };
+        let part = match part.parse() {
+            Ok(part @ (1 | 2)) => part,
+            _ => error("invalid part"),
+        };
+        let Ok(expected) = answer.parse() else {
+            error("invalid answer");
+        };
+        let Ok(input) = std::fs::read_to_string(dir.join(format!("{name}.txt"))) else {
+            error("cannot read example");
+        };
+        examples.push(Example {
+            name: name.to_owned(),
+            part,
+            input,
+            expected,
+        });
+    }
+
+    for entry in std::fs::read_dir(dir).into_iter().flatten().flatten() {
+        let path = entry.path();
+        if path.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+        let name = path.file_stem().unwrap_or_default().to_string_lossy();
+        if !examples.iter().any(|example| example.name == name) {
+            panic!("example '{}' has no expected answer", path.display());
+        }
+    }
+    examples
+}
+
+/// Generates a test that runs the `run` functions of both parts on every example in the
+/// `examples` directory of the crate.
+#[macro_export]
+macro_rules! example_tests {
+    ($part1:path, $part2:path) => {
+        #[test]
+        fn test_examples() {
+            let dir = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+            let examples = $crate::examples::load(&dir);
+            assert!(!examples.is_empty(), "no examples in '{}'", dir.display());
+
+            let mut failures = Vec::new();
+            for example in examples {
+                let answer = match example.part {
+                    1 => $part1(&example.input).map($crate::Answer::from),
+                    _ => $part2(&example.input).map($crate::Answer::from),
+                };
+                match answer {
+                    Ok(answer) if answer == example.expected => {}
+                    Ok(answer) => failures.push(format!(
+                        "part {} of '{}': expected {}, got {answer}",
+                        example.part, example.name, example.expected
+                    )),
+                    Err(err) => failures.push(format!(
+                        "part {} of '{}': {err}",
+                        example.part, example.name
+                    )),
+                }
+            }
+            assert!(failures.is_empty(), "{}", failures.join("\n"));
+        }
+    };
+}
@@ -0,0 +1,143 @@
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable that is used when no input is given on the command line.
+pub const INPUT_ENV_VAR: &str = "AOC_INPUT";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// `-` means stdin, anything else is a file path
+    pub fn parse(arg: &str) -> Self {
+        match arg {
+            "-" => Self::Stdin,
+            path => Self::File(path.into()),
+        }
+    }
+
+    /// Picks the command line argument, then `AOC_INPUT`, then the given default path.
+    pub fn select(arg: Option<&str>, default: impl AsRef<Path>) -> Self {
+        if let Some(arg) = arg {
+            return Self::parse(arg);
+        }
+        match std::env::var(INPUT_ENV_VAR) {
+            Ok(var) if !var.is_empty() => Self::parse(&var),
+            _ => Self::File(default.as_ref().to_owned()),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let to_error = |error| InputError {
+            source: self.clone(),
+            error,
+        };
+        match self {
+            Self::File(path) => std::fs::read_to_string(path).map_err(to_error),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map_err(to_error)?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Opens the input for reading it bit by bit instead of as a whole.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            Self::File(path) => match std::fs::File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(error) => Err(InputError {
+                    source: self.clone(),
+                    error,
+                }),
+            },
+            Self::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "'{}'", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: InputSource,
+    error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot read input from {}: {}", self.source, self.error)
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Reads the selected input, or prints the error and exits the process.
+pub fn read_or_exit(arg: Option<&str>, default: impl AsRef<Path>) -> String {
+    InputSource::select(arg, default)
+        .read()
+        .unwrap_or_else(|err| {
+            eprintln!("{err}");
+            std::process::exit(1);
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(InputSource::parse("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::parse("./input"),
+            InputSource::File("./input".into())
+        );
+    }
+
+    #[test]
+    fn test_select_arg() {
+        let source = InputSource::select(Some("other"), "./input");
+        assert_eq!(source, InputSource::File("other".into()));
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let source = InputSource::File("./does/not/exist".into());
+        let err = source.read().unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("cannot read input from './does/not/exist': "));
+    }
+
+    #[test]
+    fn test_open() {
+        let source = InputSource::File(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").into());
+        let first = source.open().unwrap().lines().next().unwrap().unwrap();
+        assert_eq!(first, "[package]");
+        let source = InputSource::File("./does/not/exist".into());
+        assert!(source.open().is_err());
+    }
+
+    #[test]
+    fn test_read_file() {
+        let source = InputSource::File(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").into());
+        assert!(source.read().unwrap().contains("name = \"common\""));
+    }
+}
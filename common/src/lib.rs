@@ -0,0 +1,7 @@
+pub mod examples;
+pub mod input;
+pub mod parse;
+pub mod solution;
+
+pub use parse::{ParseError, ParseErrorKind};
+pub use solution::{Answer, Solution};
@@ -0,0 +1,139 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// not a number, or out of range for its type
+    InvalidNumber,
+    UnexpectedChar,
+    /// something required is missing, e.g. a separator or a value
+    Missing(&'static str),
+    /// a well formed value that is not allowed here
+    Invalid(&'static str),
+    /// a grid row that is not as wide as the rows before it
+    RowWidth {
+        expected: usize,
+        found: usize,
+    },
+}
+
+/// Error of a puzzle parser, pointing at the offending text in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    /// 1-based, 0 if not known yet
+    pub line: usize,
+    /// 1-based, counted in chars
+    pub column: usize,
+    pub text: String,
+}
+
+impl ParseError {
+    /// `text` must be a slice of `line`, the column is computed from its position in there.
+    pub fn new(kind: ParseErrorKind, line: &str, text: &str) -> Self {
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|offset| *offset <= line.len())
+            .unwrap_or(0);
+        Self {
+            kind,
+            line: 0,
+            column: line[..offset].chars().count() + 1,
+            text: text.to_owned(),
+        }
+    }
+
+    /// Error about something missing at the end of the input.
+    pub fn at_end(kind: ParseErrorKind, input: &str) -> Self {
+        let (line, last) = lines(input).last().unwrap_or((1, ""));
+        Self {
+            kind,
+            line,
+            column: last.chars().count() + 1,
+            text: String::new(),
+        }
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+}
+
+/// lets infallible conversions like `char: TryFrom<char>` be used by fallible parsers
+impl From<Infallible> for ParseErrorKind {
+    fn from(never: Infallible) -> Self {
+        match never {}
+    }
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNumber => write!(f, "invalid number"),
+            Self::UnexpectedChar => write!(f, "unexpected character"),
+            Self::Missing(what) => write!(f, "missing {what}"),
+            Self::Invalid(what) => write!(f, "invalid {what}"),
+            Self::RowWidth { expected, found } => {
+                write!(f, "row of width {found}, expected width {expected}")
+            }
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " in '{}'", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Iterates over the lines of the input, numbered starting at 1.
+pub fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(i, line)| (i + 1, line))
+}
+
+/// Parses `token`, which must be a slice of `line`.
+pub fn number<T: FromStr>(line: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(ParseErrorKind::InvalidNumber, line, token))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column() {
+        let line = "  12 x4 ";
+        let err = number::<u8>(line, &line[5..7]).unwrap_err().on_line(3);
+        assert_eq!(err.kind, ParseErrorKind::InvalidNumber);
+        assert_eq!((err.line, err.column), (3, 6));
+        assert_eq!(err.to_string(), "line 3, column 6: invalid number in 'x4'");
+    }
+
+    #[test]
+    fn test_column_in_chars() {
+        let line = "äö#";
+        let err = ParseError::new(ParseErrorKind::UnexpectedChar, line, &line[4..]);
+        assert_eq!(err.column, 3);
+    }
+
+    #[test]
+    fn test_at_end() {
+        let err = ParseError::at_end(ParseErrorKind::Missing("guard"), "...\n..\n");
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.to_string(), "line 2, column 3: missing guard");
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// A puzzle solution, split into parsing the input and solving both parts.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+/// The answer of a puzzle part, wide enough to hold any of the integer results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Answer(i128);
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Self(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i32, i64, i128, u32, u64, usize);
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for Answer {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.parse()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_from() {
+        assert_eq!(Answer::from(-3_i32), Answer::from(-3_i64));
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(Answer::from(usize::MAX).to_string(), usize::MAX.to_string());
+    }
+
+    #[test]
+    fn test_answer_from_str() {
+        assert_eq!("8401132154762".parse(), Ok(Answer::from(8401132154762_u64)));
+        assert_eq!("-1".parse(), Ok(Answer::from(-1)));
+        assert!("12a".parse::<Answer>().is_err());
+    }
+}
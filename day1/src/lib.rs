@@ -0,0 +1,28 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+pub mod stream;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = part1::Lists;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::Lists::parse(input)
+    }
+
+    fn part1(lists: &Self::Input) -> Answer {
+        part1::solve(lists).into()
+    }
+
+    fn part2(lists: &Self::Input) -> Answer {
+        part2::solve(lists).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::example_tests!(crate::part1::run, crate::part2::run);
+}
@@ -1,16 +1,12 @@
-#[allow(dead_code)]
-pub fn run(input: &str) -> i64 {
-    let mut list1: Vec<i64> = input
-        .split_whitespace()
-        .step_by(2)
-        .map(|num| num.parse().unwrap())
-        .collect();
-    let mut list2: Vec<i64> = input
-        .split_whitespace()
-        .skip(1)
-        .step_by(2)
-        .map(|num| num.parse().unwrap())
-        .collect();
+use common::parse::{self, ParseError, ParseErrorKind};
+
+pub fn run(input: &str) -> Result<i64, ParseError> {
+    Ok(solve(&Lists::parse(input)?))
+}
+
+pub fn solve(lists: &Lists) -> i64 {
+    let mut list1 = lists.left.clone();
+    let mut list2 = lists.right.clone();
 
     list1.sort();
     list2.sort();
@@ -23,21 +19,59 @@ pub fn run(input: &str) -> i64 {
         .sum()
 }
 
+#[derive(Debug, Clone)]
+pub struct Lists {
+    pub left: Vec<i64>,
+    pub right: Vec<i64>,
+}
+
+impl Lists {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        for (line_no, line) in parse::lines(input) {
+            if let Some((first, second)) = parse_line(line).map_err(|err| err.on_line(line_no))? {
+                left.push(first);
+                right.push(second);
+            }
+        }
+        Ok(Self { left, right })
+    }
+}
+
+/// Parses the two numbers of a line, `None` if the line is blank.
+pub fn parse_line(line: &str) -> Result<Option<(i64, i64)>, ParseError> {
+    let mut nums = line.split_whitespace();
+    let Some(first) = nums.next() else {
+        return Ok(None);
+    };
+    let second = nums.next().ok_or_else(|| {
+        ParseError::new(ParseErrorKind::Missing("second number"), line, line.trim())
+    })?;
+    if let Some(extra) = nums.next() {
+        let kind = ParseErrorKind::Invalid("third number");
+        return Err(ParseError::new(kind, line, extra));
+    }
+    Ok(Some((
+        parse::number(line, first)?,
+        parse::number(line, second)?,
+    )))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const EXAMPLE: &str = r#"
-        3   4
-        4   3
-        2   5
-        1   3
-        3   9
-        3   3
-    "#;
-
     #[test]
-    fn test_example() {
-        assert_eq!(run(EXAMPLE), 11);
+    fn test_parse_errors() {
+        let err = Lists::parse("1   2\n3   x4\n").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::InvalidNumber);
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 5, "x4"));
+
+        let err = Lists::parse("1   2\n  3\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: missing second number in '3'"
+        );
     }
 }
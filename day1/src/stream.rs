@@ -0,0 +1,221 @@
+//! Both parts in a single pass over a reader, for inputs too large to hold in memory.
+//!
+//! Sorting both lists and pairing them up is the same as pairing up the counts of each value
+//! in ascending order, so only the counts are kept, in one flat array per list. Values must lie
+//! in `0..=max_value`, memory is fixed by that bound and does not grow with the input.
+
+use std::fmt;
+use std::io::{self, BufRead};
+
+use common::{ParseError, ParseErrorKind};
+
+use crate::part1;
+
+/// The puzzle has numbers of at most five digits, which takes two arrays of 800 kB.
+pub const MAX_VALUE: usize = 99_999;
+
+/// How often each value occurs in the left and in the right list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counts {
+    left: Vec<u64>,
+    right: Vec<u64>,
+    pairs: u64,
+}
+
+impl Default for Counts {
+    fn default() -> Self {
+        Self::new(MAX_VALUE)
+    }
+}
+
+impl Counts {
+    /// Counts values in `0..=max_value`, allocating two arrays of `max_value + 1` counts.
+    pub fn new(max_value: usize) -> Self {
+        Self {
+            left: vec![0; max_value + 1],
+            right: vec![0; max_value + 1],
+            pairs: 0,
+        }
+    }
+
+    /// Reads with the bound [`MAX_VALUE`].
+    pub fn read(reader: impl BufRead) -> Result<Self, StreamError> {
+        let mut counts = Self::default();
+        counts.read_from(reader)?;
+        Ok(counts)
+    }
+
+    /// Reads line by line into one reused buffer. Blank lines are skipped, a value outside of
+    /// the bound is an error on its line.
+    pub fn read_from(&mut self, mut reader: impl BufRead) -> Result<(), StreamError> {
+        let mut buf = Vec::new();
+        let mut line_no = 0;
+        loop {
+            buf.clear();
+            if reader.read_until(b'\n', &mut buf)? == 0 {
+                return Ok(());
+            }
+            line_no += 1;
+            let line = decode(&buf)
+                .map_err(|err| err.on_line(line_no))?
+                .trim_end_matches(['\n', '\r']);
+            let pair = part1::parse_line(line).map_err(|err| err.on_line(line_no))?;
+            if let Some((left, right)) = pair {
+                self.add(left, right)
+                    .map_err(|kind| ParseError::new(kind, line, line.trim()).on_line(line_no))?;
+            }
+        }
+    }
+
+    /// Fails without counting either value if one of them is out of bounds.
+    pub fn add(&mut self, left: i64, right: i64) -> Result<(), ParseErrorKind> {
+        let index = |value: i64| {
+            usize::try_from(value)
+                .ok()
+                .filter(|index| *index < self.left.len())
+                .ok_or(ParseErrorKind::Invalid(
+                    "pair, a number is out of the counted range",
+                ))
+        };
+        let (left, right) = (index(left)?, index(right)?);
+        self.left[left] += 1;
+        self.right[right] += 1;
+        self.pairs += 1;
+        Ok(())
+    }
+
+    /// The number of lines with a pair of numbers.
+    pub fn pairs(&self) -> u64 {
+        self.pairs
+    }
+
+    /// Part 1: the total distance between the sorted lists.
+    pub fn distance(&self) -> i128 {
+        let mut left = occurring(&self.left);
+        let mut right = occurring(&self.right);
+        let (mut l, mut r) = (left.next(), right.next());
+        let mut total = 0;
+        while let (Some((l_value, l_count)), Some((r_value, r_count))) = (&mut l, &mut r) {
+            let n = (*l_count).min(*r_count);
+            total += (i128::from(*l_value) - i128::from(*r_value)).abs() * i128::from(n);
+            *l_count -= n;
+            *r_count -= n;
+            if *l_count == 0 {
+                l = left.next();
+            }
+            if *r_count == 0 {
+                r = right.next();
+            }
+        }
+        total
+    }
+
+    /// Part 2: every left value times how often it occurs in the right list.
+    pub fn similarity(&self) -> i128 {
+        occurring(&self.left)
+            .map(|(value, count)| {
+                i128::from(value) * i128::from(count) * i128::from(self.right[value as usize])
+            })
+            .sum()
+    }
+}
+
+/// The values with a count, in ascending order.
+fn occurring(counts: &[u64]) -> impl Iterator<Item = (u64, u64)> + use<'_> {
+    counts
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| **count > 0)
+        .map(|(value, count)| (value as u64, *count))
+}
+
+/// The error points at the first byte that is not UTF-8.
+fn decode(bytes: &[u8]) -> Result<&str, ParseError> {
+    std::str::from_utf8(bytes).map_err(|err| {
+        let valid = std::str::from_utf8(&bytes[..err.valid_up_to()]).unwrap();
+        ParseError::new(
+            ParseErrorKind::Invalid("UTF-8"),
+            valid,
+            &valid[valid.len()..],
+        )
+    })
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(err: ParseError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "cannot read input: {err}"),
+            Self::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Parse(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part2;
+
+    const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn test_same_as_parts() {
+        let counts = Counts::read(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(counts.pairs(), 6);
+        assert_eq!(counts.distance(), part1::run(EXAMPLE).unwrap().into());
+        assert_eq!(counts.similarity(), part2::run(EXAMPLE).unwrap().into());
+    }
+
+    #[test]
+    fn test_uneven_counts() {
+        let counts = Counts::read("5 1\r\n\r\n5 2\n5 2\n1 9".as_bytes()).unwrap();
+        // sorted: 1 5 5 5 against 1 2 2 9
+        assert_eq!(counts.distance(), 3 + 3 + 4);
+        assert_eq!(counts.similarity(), 1);
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = Counts::read("1   2\n\n3   x4\n".as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "line 3, column 5: invalid number in 'x4'");
+        let err = Counts::read(&b"1   2\n4 \xff3\n"[..]).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 3: invalid UTF-8");
+
+        let err = Counts::read("1   2\n3   100000\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: invalid pair, a number is out of the counted range in '3   100000'"
+        );
+        let mut counts = Counts::new(10);
+        assert!(counts.add(-1, 2).is_err());
+        assert!(counts.add(3, 11).is_err());
+        assert_eq!(counts.add(10, 0), Ok(()));
+        assert_eq!((counts.pairs(), counts.distance()), (1, 10));
+    }
+}
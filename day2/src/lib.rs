@@ -0,0 +1,27 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<part1::Report>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::parse(input)
+    }
+
+    fn part1(reports: &Self::Input) -> Answer {
+        part1::solve(reports).into()
+    }
+
+    fn part2(reports: &Self::Input) -> Answer {
+        part2::solve(reports).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::example_tests!(crate::part1::run, crate::part2::run);
+}
@@ -1,21 +1,34 @@
-#[allow(dead_code)]
-pub fn run(input: &str) -> usize {
-    input
-        .lines()
-        .map(Report::from_line)
-        .filter(|report| report.is_safe())
-        .count()
+use common::parse::{self, ParseError};
+
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse(input)?))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Report>, ParseError> {
+    parse::lines(input)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(line_no, line)| Report::parse(line).map_err(|err| err.on_line(line_no)))
+        .collect()
+}
+
+pub fn solve(reports: &[Report]) -> usize {
+    reports.iter().filter(|report| report.is_safe()).count()
 }
 
-struct Report(Vec<i32>);
+#[derive(Debug, Clone)]
+pub struct Report(Vec<i32>);
 
 impl Report {
-    fn from_line(line: &str) -> Self {
-        let x = line
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let levels = line
             .split_whitespace()
-            .map(|s| s.parse().unwrap())
-            .collect::<Vec<_>>();
-        Self(x)
+            .map(|s| parse::number(line, s))
+            .collect::<Result<_, _>>()?;
+        Ok(Self(levels))
+    }
+
+    pub fn levels(&self) -> &[i32] {
+        &self.0
     }
 
     fn is_safe(&self) -> bool {
@@ -39,17 +52,9 @@ impl Report {
 mod tests {
     use super::*;
 
-    const EXAMPLE: &str = r#"
-        7 6 4 2 1
-        1 2 7 8 9
-        9 7 6 2 1
-        1 3 2 4 5
-        8 6 4 4 1
-        1 3 6 7 9
-    "#;
-
     #[test]
-    fn test_example() {
-        assert_eq!(run(EXAMPLE), 2);
+    fn test_parse_error() {
+        let err = parse("1 2 3\n4 5 6 -\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 7: invalid number in '-'");
     }
 }
@@ -1,34 +1,27 @@
-pub fn run(input: &str) -> usize {
-    input
-        .lines()
-        .map(Report::from_line)
-        .filter(|report| report.is_safe())
-        .count()
-}
+use common::ParseError;
 
-struct Report(Vec<i32>);
+use crate::part1::{self, Report};
 
-impl Report {
-    fn from_line(line: &str) -> Self {
-        let x = line
-            .split_whitespace()
-            .map(|s| s.parse().unwrap())
-            .collect::<Vec<_>>();
-        Self(x)
-    }
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&part1::parse(input)?))
+}
 
-    fn dampened(&self) -> impl Iterator<Item = DampenedReport> {
-        (0..self.0.len()).map(|i| DampenedReport::new(&self.0, i))
-    }
+pub fn solve(reports: &[Report]) -> usize {
+    reports.iter().filter(|report| is_safe(report)).count()
+}
 
-    fn is_safe(&self) -> bool {
-        for dampened_report in self.dampened() {
-            if dampened_report.is_safe() {
-                return true;
-            }
+fn dampened(report: &Report) -> impl Iterator<Item = DampenedReport<'_>> {
+    let levels = report.levels();
+    (0..levels.len()).map(|i| DampenedReport::new(levels, i))
+}
+
+fn is_safe(report: &Report) -> bool {
+    for dampened_report in dampened(report) {
+        if dampened_report.is_safe() {
+            return true;
         }
-        false
     }
+    false
 }
 
 struct DampenedReport<'a> {
@@ -82,22 +75,3 @@ impl<'a> DampenedReport<'a> {
         true
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE: &str = r#"
-        7 6 4 2 1
-        1 2 7 8 9
-        9 7 6 2 1
-        1 3 2 4 5
-        8 6 4 4 1
-        1 3 6 7 9
-    "#;
-
-    #[test]
-    fn test_example() {
-        assert_eq!(run(EXAMPLE), 4);
-    }
-}
@@ -0,0 +1,36 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day3;
+
+/// The multiplications of the corrupted memory, parsed for both parts.
+pub struct Memory {
+    pub all: Option<part1::Expr>,
+    pub enabled: Option<part1::Expr>,
+}
+
+impl Solution for Day3 {
+    type Input = Memory;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Memory {
+            all: part1::parse(input)?,
+            enabled: part2::parse(input)?,
+        })
+    }
+
+    fn part1(memory: &Self::Input) -> Answer {
+        part1::solve(memory.all.as_ref()).into()
+    }
+
+    fn part2(memory: &Self::Input) -> Answer {
+        part1::solve(memory.enabled.as_ref()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::example_tests!(crate::part1::run, crate::part2::run);
+}
@@ -1,11 +1,36 @@
 use chumsky::prelude::*;
+use common::{ParseError, ParseErrorKind};
 
-pub fn run(input: &str) -> i32 {
+pub fn run(input: &str) -> Result<i32, ParseError> {
+    Ok(solve(parse(input)?.as_ref()))
+}
+
+pub fn parse(input: &str) -> Result<Option<Expr>, ParseError> {
     parser()
         .parse(input)
-        .unwrap()
-        .unwrap_or(Expr::Num(0))
-        .eval()
+        .map_err(|errors| to_parse_error(input, &errors[0]))
+}
+
+pub fn solve(expr: Option<&Expr>) -> i32 {
+    expr.map_or(0, Expr::eval)
+}
+
+/// converts the char based span of a parser error into line and column
+pub(crate) fn to_parse_error(input: &str, error: &Simple<char>) -> ParseError {
+    let offset = error.span().start;
+    let before: String = input.chars().take(offset).collect();
+    let line = before.matches('\n').count() + 1;
+    let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+    let (kind, text) = match error.found() {
+        Some(c) => (ParseErrorKind::UnexpectedChar, c.to_string()),
+        None => (ParseErrorKind::Missing("instructions"), String::new()),
+    };
+    ParseError {
+        kind,
+        line,
+        column,
+        text,
+    }
 }
 
 #[derive(Debug)]
@@ -27,11 +52,9 @@ impl Expr {
 
 fn parser() -> impl Parser<char, Option<Expr>, Error = Simple<char>> {
     let parse_crap = any().ignored().map(|_| None);
-    let parse_int = text::int(10).map(|s: String| Expr::Num(s.parse().unwrap()));
-
-    let parse_mul = parse_int
+    let parse_mul = parse_int()
         .then_ignore(just(','))
-        .then(parse_int)
+        .then(parse_int())
         .delimited_by(just("mul("), just(')'))
         .map(|(a, b)| Some(Expr::Mul(Box::new(a), Box::new(b))));
 
@@ -44,32 +67,43 @@ fn parser() -> impl Parser<char, Option<Expr>, Error = Simple<char>> {
         (Some(a), Some(b)) => Some(Expr::Add(Box::new(a), Box::new(b))),
     };
 
-    parse_some.then(parse_some.repeated()).foldl(fold_func)
+    parse_some
+        .clone()
+        .then(parse_some.repeated())
+        .foldl(fold_func)
+}
+
+/// numbers that don't fit are not a valid instruction, so they are just corrupted memory
+pub(crate) fn parse_int() -> impl Parser<char, Expr, Error = Simple<char>> + Clone {
+    text::int(10).try_map(|s: String, span| {
+        s.parse()
+            .map(Expr::Num)
+            .map_err(|_| Simple::custom(span, "number too large"))
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const EXAMPLE: &str =
-        r#"xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))"#;
-
     #[test]
-    fn test_part1() {
-        assert_eq!(161, run(EXAMPLE));
+    fn test_good() {
+        assert_eq!(44 * 46, run("mul(44,46)").unwrap());
+        assert_eq!(123 * 4, run("mul(123,4)").unwrap());
     }
 
     #[test]
-    fn test_good() {
-        assert_eq!(44 * 46, run("mul(44,46)"));
-        assert_eq!(123 * 4, run("mul(123,4)"));
+    fn test_bad() {
+        assert_eq!(0, run("mul(4*").unwrap());
+        assert_eq!(0, run("mul(6,9!").unwrap());
+        assert_eq!(0, run("?(12,34)").unwrap());
+        assert_eq!(0, run("mul ( 2 , 4 )").unwrap());
+        assert_eq!(0, run("mul(99999999999,2)").unwrap());
     }
 
     #[test]
-    fn test_bad() {
-        assert_eq!(0, run("mul(4*"));
-        assert_eq!(0, run("mul(6,9!"));
-        assert_eq!(0, run("?(12,34)"));
-        assert_eq!(0, run("mul ( 2 , 4 )"));
+    fn test_empty() {
+        let err = run("").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 1: missing instructions");
     }
 }
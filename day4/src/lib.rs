@@ -0,0 +1,28 @@
+use common::{Answer, ParseError, Solution};
+use grid::Plane;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Plane<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Plane::parse(input)
+    }
+
+    fn part1(plane: &Self::Input) -> Answer {
+        part1::solve(plane).into()
+    }
+
+    fn part2(plane: &Self::Input) -> Answer {
+        part2::solve(plane).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::example_tests!(crate::part1::run, crate::part2::run);
+}
@@ -1,23 +1,23 @@
-use strum::IntoEnumIterator;
+use common::ParseError;
+use grid::{Coords, Direction, Plane};
 
-use crate::plane::{self, Plane};
-
-pub fn run(input: &str) -> usize {
-    let plane = Plane::parse(input);
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&Plane::parse(input)?))
+}
 
+pub fn solve(plane: &Plane<char>) -> usize {
     plane
         .iter_coords()
-        .flat_map(|coords| plane::Direction::iter().map(move |direction| (coords, direction)))
-        .filter(|(coords, direction)| has_str(&plane, "XMAS", *coords, *direction))
+        .flat_map(|coords| {
+            Direction::ALL
+                .into_iter()
+                .map(move |direction| (coords, direction))
+        })
+        .filter(|(coords, direction)| has_str(plane, "XMAS", *coords, *direction))
         .count()
 }
 
-fn has_str(
-    plane: &Plane<char>,
-    s: &str,
-    coords: plane::Coords,
-    direction: plane::Direction,
-) -> bool {
+fn has_str(plane: &Plane<char>, s: &str, coords: Coords, direction: Direction) -> bool {
     let mut coords = coords;
     let mut chars_iter = s.chars();
 
@@ -44,43 +44,3 @@ fn has_str(
 
     true
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_1() {
-        let example = r#"
-            ..X...
-            .SAMX.
-            .A..A.
-            XMAS.S
-            .X....
-        "#;
-        assert_eq!(4, run(example));
-    }
-
-    #[test]
-    fn test_2() {
-        let example = r#"
-            MMMSXXMASM
-            MSAMXMSMSA
-            AMXSXMAAMM
-            MSAMASMSMX
-            XMASAMXAMM
-            XXAMMXXAMA
-            SMSMSASXSS
-            SAXAMASAAA
-            MAMMMXMMMM
-            MXMXAXMASX
-        "#;
-        assert_eq!(18, run(example));
-    }
-
-    #[test]
-    fn test_3() {
-        let example = "SAMX";
-        assert_eq!(1, run(example));
-    }
-}
@@ -1,61 +1,28 @@
-use crate::plane::{self, Plane};
+use common::ParseError;
+use grid::{Plane, View};
 
-pub fn run(input: &str) -> usize {
-    let plane = Plane::parse(input);
+/// One orientation of the X-MAS, '.' matches anything.
+const X_MAS: &str = "\
+    M.S\n\
+    .A.\n\
+    M.S\n\
+";
 
-    plane
-        .iter_coords()
-        .filter(|coords| is_x(&plane, *coords))
-        .count()
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&Plane::parse(input)?))
 }
 
-fn is_x(plane: &Plane<char>, coords: plane::Coords) -> bool {
-    // first check 'A' in the middle
-    let Some(contained) = plane.get(coords) else {
-        return false;
-    };
-    if *contained != 'A' {
-        return false;
-    }
-
-    // now get the surrounding chars
-    let directions = [
-        plane::Direction::UpLeft,
-        plane::Direction::UpRight,
-        plane::Direction::DownRight,
-        plane::Direction::DownLeft,
-    ];
-    let x: String = directions
-        .into_iter()
-        .filter_map(|direction| coords.move_into_direction(direction))
-        .filter_map(|coords| plane.get(coords))
-        .collect();
-    if x.len() < 4 {
-        return false;
-    }
-
-    // now check the surrounding chars
-    matches!(x.as_ref(), "MMSS" | "SMMS" | "SSMM" | "MSSM")
+pub fn solve(plane: &Plane<char>) -> usize {
+    let patterns = Plane::parse(X_MAS).unwrap().rotations();
+    plane
+        .windows(3, 3)
+        .filter(|window| patterns.iter().any(|pattern| matches(window, pattern)))
+        .count()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_example() {
-        let example = "\
-            .M.S...... \n\
-            ..A..MSMS. \n\
-            .M.S.MAA.. \n\
-            ..A.ASMSM. \n\
-            .M.S.M.... \n\
-            .......... \n\
-            S.S.S.S.S. \n\
-            .A.A.A.A.. \n\
-            M.M.M.M.M. \n\
-            .......... \n\
-            ";
-        assert_eq!(9, run(example));
-    }
+fn matches(window: &View<'_, char>, pattern: &Plane<char>) -> bool {
+    window
+        .iter()
+        .zip(pattern.iter_entries())
+        .all(|((_, c), expected)| *expected == '.' || c == expected)
 }
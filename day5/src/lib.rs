@@ -0,0 +1,27 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = part1::PrintQueue;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::PrintQueue::parse(input)
+    }
+
+    fn part1(queue: &Self::Input) -> Answer {
+        part1::solve(queue).into()
+    }
+
+    fn part2(queue: &Self::Input) -> Answer {
+        part2::solve(queue).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::example_tests!(crate::part1::run, crate::part2::run);
+}
@@ -1,27 +1,89 @@
 use std::cmp::Ordering;
 
-pub fn run(input: &str) -> u32 {
-    let deps = PageDependencies::load_from_input(input);
-    input
-        .lines()
-        .filter_map(PageUpdate::parse)
-        .filter(|update| update.is_ok(&deps))
+use common::parse::{self, ParseError, ParseErrorKind};
+
+pub fn run(input: &str) -> Result<u32, ParseError> {
+    Ok(solve(&PrintQueue::parse(input)?))
+}
+
+pub fn solve(queue: &PrintQueue) -> u32 {
+    queue
+        .updates
+        .iter()
+        .filter(|update| update.is_ok(&queue.deps))
         .map(|update| update.get_middle_page())
         .map(u32::from)
         .sum()
 }
 
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrintQueue {
+    pub deps: PageDependencies,
+    pub updates: Vec<PageUpdate>,
+}
+
+impl PrintQueue {
+    /// rules and updates are separated by an empty line
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = parse::lines(input).skip_while(|(_, line)| line.trim().is_empty());
+
+        let mut deps = PageDependencies::default();
+        for (line_no, line) in lines.by_ref() {
+            if line.trim().is_empty() {
+                break;
+            }
+            let rule = Rule::parse(line).map_err(|err| err.on_line(line_no))?;
+            if deps.contains(rule.reversed()) {
+                let kind = ParseErrorKind::Invalid("rule, it contradicts an earlier one");
+                return Err(ParseError::new(kind, line, line.trim()).on_line(line_no));
+            }
+            deps.add(rule);
+        }
+
+        let updates = lines
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(line_no, line)| PageUpdate::parse(line).map_err(|err| err.on_line(line_no)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { deps, updates })
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "Vec<Page>", try_from = "Vec<Page>")
+)]
 pub struct PageUpdate(Vec<Page>);
 
-impl PageUpdate {
-    pub fn parse(line: &str) -> Option<PageUpdate> {
-        let pages: Vec<_> = line.split(',').filter_map(Page::parse).collect();
-        if pages.is_empty() {
-            None
-        } else {
-            assert_eq!(pages.len() & 1, 1);
-            Some(PageUpdate(pages))
+impl From<PageUpdate> for Vec<Page> {
+    fn from(update: PageUpdate) -> Self {
+        update.0
+    }
+}
+
+impl TryFrom<Vec<Page>> for PageUpdate {
+    type Error = ParseErrorKind;
+
+    fn try_from(pages: Vec<Page>) -> Result<Self, Self::Error> {
+        if pages.len() & 1 == 0 {
+            return Err(ParseErrorKind::Invalid(
+                "update, it needs an odd number of pages",
+            ));
         }
+        Ok(Self(pages))
+    }
+}
+
+impl PageUpdate {
+    pub fn parse(line: &str) -> Result<PageUpdate, ParseError> {
+        let pages: Vec<_> = line
+            .trim()
+            .split(',')
+            .map(|page| Page::parse(line, page.trim()))
+            .collect::<Result<_, _>>()?;
+        PageUpdate::try_from(pages).map_err(|kind| ParseError::new(kind, line, line.trim()))
     }
 
     pub fn into_sorted(self, rules: &PageDependencies) -> Self {
@@ -49,8 +111,30 @@ impl PageUpdate {
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "u8", try_from = "u8")
+)]
 pub struct Page(u8);
 
+impl From<Page> for u8 {
+    fn from(page: Page) -> Self {
+        page.0
+    }
+}
+
+impl TryFrom<u8> for Page {
+    type Error = ParseErrorKind;
+
+    fn try_from(num: u8) -> Result<Self, Self::Error> {
+        if num >= 100 {
+            return Err(ParseErrorKind::Invalid("page, it must be below 100"));
+        }
+        Ok(Self(num))
+    }
+}
+
 impl From<Page> for u32 {
     fn from(value: Page) -> Self {
         value.0.into()
@@ -58,27 +142,84 @@ impl From<Page> for u32 {
 }
 
 impl Page {
-    fn parse(s: &str) -> Option<Page> {
-        Some(Self(s.parse().ok()?))
+    /// `s` must be a slice of `line`
+    fn parse(line: &str, s: &str) -> Result<Page, ParseError> {
+        let num: u8 = parse::number(line, s)?;
+        Self::try_from(num).map_err(|kind| ParseError::new(kind, line, s))
     }
 }
 
-#[derive(Debug, Default, Clone)]
+/// Serialized as its list of rules rather than the bit sets.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "Vec<Rule>", try_from = "Vec<Rule>")
+)]
 pub struct PageDependencies(Vec<u128>);
 
-impl PageDependencies {
-    pub fn load_from_input(input: &str) -> Self {
-        let rules = input.lines().filter_map(Rule::parse);
-        Self::construct_from_rules(rules)
+impl From<PageDependencies> for Vec<Rule> {
+    fn from(deps: PageDependencies) -> Self {
+        deps.rules().collect()
     }
+}
+
+impl TryFrom<Vec<Rule>> for PageDependencies {
+    type Error = ParseErrorKind;
+
+    fn try_from(rules: Vec<Rule>) -> Result<Self, Self::Error> {
+        let mut deps = Self::default();
+        for rule in rules {
+            Page::try_from(rule.0)?;
+            Page::try_from(rule.1)?;
+            if rule.0 == rule.1 {
+                return Err(ParseErrorKind::Invalid(
+                    "rule, a page cannot come before itself",
+                ));
+            }
+            if deps.contains(rule.reversed()) {
+                return Err(ParseErrorKind::Invalid(
+                    "rule, it contradicts an earlier one",
+                ));
+            }
+            deps.add(rule);
+        }
+        Ok(deps)
+    }
+}
 
+impl Default for PageDependencies {
+    fn default() -> Self {
+        Self(vec![0; 100])
+    }
+}
+
+impl PageDependencies {
     pub fn construct_from_rules(rules: impl Iterator<Item = Rule>) -> Self {
-        let mut dependencies = vec![0; 100];
-        for Rule(dep, page_num) in rules {
-            let page = usize::from(page_num);
-            dependencies[page] |= 1 << dep;
+        let mut dependencies = Self::default();
+        for rule in rules {
+            dependencies.add(rule);
         }
-        Self(dependencies)
+        dependencies
+    }
+
+    pub fn add(&mut self, Rule(dep, page_num): Rule) {
+        let page = usize::from(page_num);
+        self.0[page] |= 1 << dep;
+    }
+
+    pub fn contains(&self, Rule(dep, page_num): Rule) -> bool {
+        let page = usize::from(page_num);
+        (self.0[page] >> dep) & 1 == 1
+    }
+
+    /// All rules, ordered by the page that has to come later.
+    pub fn rules(&self) -> impl Iterator<Item = Rule> + use<'_> {
+        self.0.iter().enumerate().flat_map(|(page, &deps)| {
+            (0..100u8)
+                .filter(move |dep| (deps >> dep) & 1 == 1)
+                .map(move |dep| Rule(dep, page as u8))
+        })
     }
 
     pub fn compare(&self, a: Page, b: Page) -> Ordering {
@@ -92,25 +233,36 @@ impl PageDependencies {
         let b_before_a = (a_deps >> b) & 1 == 1;
 
         match (a_before_b, b_before_a) {
-            (true, true) => unreachable!(),
             (true, false) => Ordering::Less,
             (false, true) => Ordering::Greater,
-            (false, false) => Ordering::Equal,
+            // both only for a page with itself, which no rule can order
+            (true, true) | (false, false) => Ordering::Equal,
         }
     }
 }
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rule(u8, u8);
 
 impl Rule {
-    pub fn parse(line: &str) -> Option<Self> {
-        let (a, b) = line.split_once('|')?;
-        let a = a.parse().ok()?;
-        let b = b.parse().ok()?;
-        assert!(a < 100);
-        assert!(b < 100);
-        Some(Self(a, b))
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let trimmed = line.trim();
+        let Some((a, b)) = trimmed.split_once('|') else {
+            let kind = ParseErrorKind::Missing("'|' in rule");
+            return Err(ParseError::new(kind, line, trimmed));
+        };
+        let a = Page::parse(line, a.trim())?;
+        let b = Page::parse(line, b.trim())?;
+        if a == b {
+            let kind = ParseErrorKind::Invalid("rule, a page cannot come before itself");
+            return Err(ParseError::new(kind, line, trimmed));
+        }
+        Ok(Self(a.0, b.0))
+    }
+
+    pub fn reversed(self) -> Self {
+        Self(self.1, self.0)
     }
 }
 
@@ -119,48 +271,69 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_example() {
-        let example = "\
-            47|53\n\
-            97|13\n\
-            97|61\n\
-            97|47\n\
-            75|29\n\
-            61|13\n\
-            75|53\n\
-            29|13\n\
-            97|29\n\
-            53|29\n\
-            61|53\n\
-            97|53\n\
-            61|29\n\
-            47|13\n\
-            75|47\n\
-            97|75\n\
-            47|61\n\
-            75|61\n\
-            47|29\n\
-            75|13\n\
-            53|13\n\
-            \n\
-            75,47,61,53,29\n\
-            97,61,53,29,13\n\
-            75,29,13\n\
-            75,97,47,61,53\n\
-            61,13,29\n\
-            97,13,75,29,47\n\
-        ;";
-        assert_eq!(143, run(example));
+    fn test_parse_errors() {
+        let err = run("12|99\n12|100\n\n12,99,1\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "100"));
+
+        let err = run("12|99\n99|12\n\n12,99,1\n").unwrap_err();
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::Invalid("rule, it contradicts an earlier one")
+        );
+
+        let err = run("12|99\n\n12,99,1\n12,99\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: invalid update, it needs an odd number of pages in '12,99'"
+        );
+
+        let err = run("1|2\n 12|12\n\n12,5,12\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: invalid rule, a page cannot come before itself in '12|12'"
+        );
+
+        let err = run("12|99\n12,99,1\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: missing '|' in rule in '12,99,1'"
+        );
     }
 
     #[test]
-    fn test_simple() {
-        let example = "\
-            12|99\n\
-            \n\
-            99,1,12\n\
-            12,2,99\n\
-        ";
-        assert_eq!(2, run(example));
+    fn test_rules() {
+        let rules = [Rule(53, 13), Rule(97, 13), Rule(61, 53)];
+        let deps = PageDependencies::construct_from_rules(rules.into_iter());
+        let mut sorted = rules.to_vec();
+        sorted.sort_by_key(|rule| (rule.1, rule.0));
+        assert_eq!(deps.rules().collect::<Vec<_>>(), sorted);
+
+        let deps = PageDependencies::construct_from_rules([Rule(12, 12)].into_iter());
+        assert_eq!(deps.compare(Page(12), Page(12)), Ordering::Equal);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let queue = PrintQueue::parse("97|13\n53|13\n\n97,53,13\n").unwrap();
+        let json = serde_json::to_string(&queue).unwrap();
+        assert_eq!(json, r#"{"deps":[[53,13],[97,13]],"updates":[[97,53,13]]}"#);
+        let back: PrintQueue = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&back).unwrap(), json);
+
+        let err = serde_json::from_str::<PrintQueue>(r#"{"deps":[[13,53],[53,13]],"updates":[]}"#)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid rule, it contradicts an earlier one"));
+        let err = serde_json::from_str::<PageDependencies>("[[12,12]]").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid rule, a page cannot come before itself"));
+        let err = serde_json::from_str::<PageUpdate>("[1,100,2]").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid page, it must be below 100"));
+        assert!(serde_json::from_str::<PageUpdate>("[1,2]").is_err());
     }
 }
@@ -1,12 +1,17 @@
-use crate::part1::{PageDependencies, PageUpdate};
+use common::ParseError;
 
-pub fn run(input: &str) -> u32 {
-    let deps = PageDependencies::load_from_input(input);
-    input
-        .lines()
-        .filter_map(PageUpdate::parse)
-        .filter(|update| !update.is_ok(&deps))
-        .map(|update| update.into_sorted(&deps))
+use crate::part1::PrintQueue;
+
+pub fn run(input: &str) -> Result<u32, ParseError> {
+    Ok(solve(&PrintQueue::parse(input)?))
+}
+
+pub fn solve(queue: &PrintQueue) -> u32 {
+    queue
+        .updates
+        .iter()
+        .filter(|update| !update.is_ok(&queue.deps))
+        .map(|update| update.clone().into_sorted(&queue.deps))
         .map(|update| update.get_middle_page())
         .map(u32::from)
         .sum()
@@ -1,18 +1,33 @@
 #![allow(unused)]
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use crate::plane::*;
+use common::{ParseError, ParseErrorKind};
+use grid::*;
 
-pub fn run(input: &str) -> usize {
-    let plane: Plane<Spot> = Plane::parse(input);
-    let mut guard = Guard::from_plane(&plane).unwrap();
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse(input)?))
+}
+
+pub fn parse(input: &str) -> Result<Plane<Spot>, ParseError> {
+    let plane = Plane::parse(input)?;
+    if Guard::from_plane(&plane).is_none() {
+        return Err(ParseError::at_end(ParseErrorKind::Missing("guard"), input));
+    }
+    Ok(plane)
+}
+
+pub fn solve(plane: &Plane<Spot>) -> usize {
+    let mut guard = Guard::from_plane(plane).unwrap();
     let mut num = 0;
     loop {
-        if guard.poop(&plane).is_ok() {
-            num += 1;
+        match guard.poop(plane) {
+            Ok(()) => num += 1,
+            Err(PoopError::DifferentDirection) => {}
+            // only possible on a wrapping plane, the guard walks in circles
+            Err(PoopError::SameDirection) => break,
         }
-        if guard.walk(&plane).is_err() {
+        if guard.walk(plane).is_err() {
             break;
         }
     }
@@ -20,22 +35,39 @@ pub fn run(input: &str) -> usize {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Spot {
     Obstacle,
     Free,
-    Start(Direction),
+    Start(Cardinal),
 }
 
-impl From<char> for Spot {
-    fn from(c: char) -> Self {
-        match c {
+impl TryFrom<char> for Spot {
+    type Error = ParseErrorKind;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        let spot = match c {
             '#' => Self::Obstacle,
             '.' => Self::Free,
-            '^' => Self::Start(Direction::Up),
-            '<' => Self::Start(Direction::Left),
-            '>' => Self::Start(Direction::Right),
-            'v' => Self::Start(Direction::Down),
-            _ => panic!("unknown char {c}"),
+            '^' => Self::Start(Cardinal::Up),
+            '<' => Self::Start(Cardinal::Left),
+            '>' => Self::Start(Cardinal::Right),
+            'v' => Self::Start(Cardinal::Down),
+            _ => return Err(ParseErrorKind::UnexpectedChar),
+        };
+        Ok(spot)
+    }
+}
+
+impl ToChar for Spot {
+    fn to_char(&self) -> char {
+        match self {
+            Self::Obstacle => '#',
+            Self::Free => '.',
+            Self::Start(Cardinal::Up) => '^',
+            Self::Start(Cardinal::Left) => '<',
+            Self::Start(Cardinal::Right) => '>',
+            Self::Start(Cardinal::Down) => 'v',
         }
     }
 }
@@ -56,10 +88,15 @@ pub enum PoopError {
     SameDirection,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Guard {
     position: Coords,
-    direction: Direction,
-    pooped: HashMap<Coords, Direction>,
+    direction: Cardinal,
+    #[cfg_attr(feature = "serde", serde(with = "pooped"))]
+    pooped: HashMap<Coords, Cardinal>,
+    /// every position the guard was at together with the direction it faced there
+    #[cfg_attr(feature = "serde", serde(with = "faced"))]
+    faced: HashSet<(Coords, Cardinal)>,
 }
 
 impl Guard {
@@ -74,15 +111,18 @@ impl Guard {
                 position,
                 direction,
                 pooped: HashMap::new(),
+                faced: HashSet::new(),
             });
         }
         None
     }
 
-    /// returns Ok if spot was free, else an error with info if the direction was faced before
+    /// returns Ok if spot was free, else an error with info if the direction was faced before,
+    /// at any visit of the spot and not just the first one
     pub fn poop(&mut self, plane: &Plane<Spot>) -> Result<(), PoopError> {
-        if let Some(direction) = self.pooped.get(&self.position) {
-            if *direction == self.direction {
+        let faced_before = !self.faced.insert((self.position, self.direction));
+        if self.pooped.contains_key(&self.position) {
+            if faced_before {
                 return Err(PoopError::SameDirection);
             }
             return Err(PoopError::DifferentDirection);
@@ -93,17 +133,18 @@ impl Guard {
     }
 
     fn turn_right(&mut self) {
-        self.direction = self.direction.rot_right().rot_right()
+        self.direction = self.direction.turn_right()
     }
 
-    /// returns `None` when end is reached
+    /// returns `Err` when the guard leaves the plane, a step that a clamped plane keeps on
+    /// the same spot counts as leaving
+    #[allow(clippy::result_unit_err)]
     pub fn walk(&mut self, plane: &Plane<Spot>) -> Result<(), ()> {
-        let next_pos = self
-            .position
-            .move_into_direction(self.direction)
-            .ok_or(())?;
-        let next_spot = plane.get(next_pos).ok_or(())?;
-        if next_spot.is_obstacle() {
+        let next_pos = plane.step(self.position, self.direction).ok_or(())?;
+        if next_pos == self.position {
+            return Err(());
+        }
+        if plane[next_pos].is_obstacle() {
             self.turn_right();
             self.walk(plane)
         } else {
@@ -112,33 +153,118 @@ impl Guard {
         }
     }
 
-    pub fn iter_pooped(&self) -> impl Iterator<Item = (Coords, Direction)> + use<'_> {
+    pub fn iter_pooped(&self) -> impl Iterator<Item = (Coords, Cardinal)> + use<'_> {
         self.pooped.iter().map(|(c, d)| (*c, *d))
     }
 
-    pub fn into_iter_pooped(self) -> impl Iterator<Item = (Coords, Direction)> {
+    pub fn into_iter_pooped(self) -> impl Iterator<Item = (Coords, Cardinal)> {
         self.pooped.into_iter()
     }
 }
 
+/// JSON maps need string keys, so the pooped spots are a list of pairs, sorted row by row
+/// to keep snapshots stable.
+#[cfg(feature = "serde")]
+mod pooped {
+    use std::collections::HashMap;
+
+    use grid::{Cardinal, Coords};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        pooped: &HashMap<Coords, Cardinal>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut pairs: Vec<_> = pooped.iter().collect();
+        pairs.sort_by_key(|(coords, _)| (coords.y, coords.x));
+        pairs.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<Coords, Cardinal>, D::Error> {
+        Ok(Vec::<(Coords, Cardinal)>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
+
+/// Sorted like the pooped spots, and clockwise from up within a spot.
+#[cfg(feature = "serde")]
+mod faced {
+    use std::collections::HashSet;
+
+    use grid::{Cardinal, Coords, Direction};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        faced: &HashSet<(Coords, Cardinal)>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut pairs: Vec<_> = faced.iter().collect();
+        pairs.sort_by_key(|(coords, direction)| {
+            (coords.y, coords.x, Direction::from(*direction).angle())
+        });
+        pairs.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashSet<(Coords, Cardinal)>, D::Error> {
+        Ok(Vec::<(Coords, Cardinal)>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_example() {
-        let input = "\
-            ....#.....\n\
-            .........#\n\
-            ..........\n\
-            ..#.......\n\
-            .......#..\n\
-            ..........\n\
-            .#..^.....\n\
-            ........#.\n\
-            #.........\n\
-            ......#...\n\
-        ";
-        assert_eq!(run(input), 41);
+    fn test_parse_errors() {
+        let err = parse("..#\n.x^\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: unexpected character in 'x'"
+        );
+        let err = parse("..#\n...\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 4: missing guard");
+    }
+
+    #[test]
+    fn test_boundaries() {
+        let plane = parse("...\n.^.\n...\n").unwrap();
+        assert_eq!(solve(&plane), 2);
+        assert_eq!(solve(&plane.clone().with_boundary(Boundary::Clamped)), 2);
+        assert_eq!(solve(&plane.with_boundary(Boundary::Wrapping)), 3);
+
+        // every cell of the cycle is first visited facing another direction
+        let plane = parse("#....\n.....\n#..#.\n#.#.^\n.#..#\n.##..\n").unwrap();
+        assert_eq!(solve(&plane.with_boundary(Boundary::Wrapping)), 9);
+
+        // up wraps onto the obstacle, then the guard circles along the top row
+        let plane = parse("...\n.^.\n.#.\n").unwrap();
+        assert_eq!(solve(&plane.with_boundary(Boundary::Wrapping)), 4);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let plane = parse("#..\n.^.\n").unwrap();
+        let mut guard = Guard::from_plane(&plane).unwrap();
+        guard.poop(&plane).unwrap();
+        guard.walk(&plane).unwrap();
+        guard.poop(&plane).unwrap();
+        let json = serde_json::to_string(&guard).unwrap();
+        assert_eq!(
+            json,
+            r#"{"position":{"x":1,"y":0},"direction":"Up","pooped":[[{"x":1,"y":0},"Up"],[{"x":1,"y":1},"Up"]],"faced":[[{"x":1,"y":0},"Up"],[{"x":1,"y":1},"Up"]]}"#
+        );
+        let back: Guard = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&back).unwrap(), json);
+
+        let json = serde_json::to_string(&plane).unwrap();
+        assert_eq!(serde_json::from_str::<Plane<Spot>>(&json).unwrap(), plane);
     }
 }
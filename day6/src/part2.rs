@@ -1,10 +1,15 @@
-use crate::part1::{Guard, PoopError, Spot};
-use crate::plane::*;
+use common::ParseError;
+use grid::*;
 
-pub fn run(input: &str) -> usize {
-    let plane: Plane<Spot> = Plane::parse(input);
-    let original_path_coords = original_path_coords(&plane);
-    plane_possibilities(plane, original_path_coords.into_iter())
+use crate::part1::{self, Guard, PoopError, Spot};
+
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&part1::parse(input)?))
+}
+
+pub fn solve(plane: &Plane<Spot>) -> usize {
+    let original_path_coords = original_path_coords(plane);
+    plane_possibilities(plane.clone(), original_path_coords.into_iter())
         .filter(contains_loop)
         .count()
 }
@@ -45,7 +50,9 @@ fn original_path_coords(plane: &Plane<Spot>) -> impl Iterator<Item = Coords> {
         if guard.walk(plane).is_err() {
             break;
         }
-        let _ = guard.poop(plane);
+        if guard.poop(plane) == Err(PoopError::SameDirection) {
+            break;
+        }
     }
 
     guard.into_iter_pooped().map(|(coords, _)| coords)
@@ -53,22 +60,30 @@ fn original_path_coords(plane: &Plane<Spot>) -> impl Iterator<Item = Coords> {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
     use super::*;
 
     #[test]
-    fn test_example() {
-        let input = "\
-            ....#.....\n\
-            .........#\n\
-            ..........\n\
-            ..#.......\n\
-            .......#..\n\
-            ..........\n\
-            .#..^.....\n\
-            ........#.\n\
-            #.........\n\
-            ......#...\n\
-        ";
-        assert_eq!(run(input), 6);
+    fn test_render_path() {
+        let plane = part1::parse(include_str!("../examples/example.txt")).unwrap();
+        let path: HashSet<_> = original_path_coords(&plane).collect();
+        let rendered = plane.render_overlay(|coords, _| path.contains(&coords).then_some('X'));
+        assert_eq!(rendered, include_str!("../examples/example.path"));
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let plane = part1::parse("...\n.^.\n...\n")
+            .unwrap()
+            .with_boundary(Boundary::Wrapping);
+        assert_eq!(original_path_coords(&plane).count(), 3);
+        // the guard walks in circles anyway, even the unchanged start position loops
+        assert_eq!(solve(&plane), 3);
+
+        let plane = part1::parse("#....\n.....\n#..#.\n#.#.^\n.#..#\n.##..\n")
+            .unwrap()
+            .with_boundary(Boundary::Wrapping);
+        assert_eq!(original_path_coords(&plane).count(), 9);
     }
 }
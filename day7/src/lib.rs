@@ -0,0 +1,27 @@
+use common::{Answer, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<part1::Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::parse(input)
+    }
+
+    fn part1(equations: &Self::Input) -> Answer {
+        part1::solve(equations).into()
+    }
+
+    fn part2(equations: &Self::Input) -> Answer {
+        part2::solve(equations).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    common::example_tests!(crate::part1::run, crate::part2::run);
+}
@@ -1,9 +1,21 @@
 #![allow(dead_code)]
 
-pub fn run(input: &str) -> usize {
-    input
-        .lines()
-        .filter_map(Equation::parse)
+use common::parse::{self, ParseError, ParseErrorKind};
+
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse(input)?))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Equation>, ParseError> {
+    parse::lines(input)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(line_no, line)| Equation::parse(line).map_err(|err| err.on_line(line_no)))
+        .collect()
+}
+
+pub fn solve(equations: &[Equation]) -> usize {
+    equations
+        .iter()
         .filter(|equation| equation.can_be_true())
         .map(|equation| equation.result())
         .sum()
@@ -16,14 +28,28 @@ pub struct Equation {
 }
 
 impl Equation {
-    pub fn parse(line: &str) -> Option<Self> {
-        let (result, numbers) = line.trim().split_once(':')?;
-        let result = result.parse().unwrap();
-        let numbers = numbers
-            .split(' ')
-            .filter_map(|num| num.parse().ok())
-            .collect();
-        Some(Self { result, numbers })
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let trimmed = line.trim();
+        let Some((result, numbers)) = trimmed.split_once(':') else {
+            return Err(ParseError::new(
+                ParseErrorKind::Missing("':'"),
+                line,
+                trimmed,
+            ));
+        };
+        let result = parse::number(line, result.trim())?;
+        let numbers: Vec<_> = numbers
+            .split_whitespace()
+            .map(|num| parse::number(line, num))
+            .collect::<Result<_, _>>()?;
+        if numbers.is_empty() {
+            return Err(ParseError::new(
+                ParseErrorKind::Missing("numbers"),
+                line,
+                trimmed,
+            ));
+        }
+        Ok(Self { result, numbers })
     }
 
     pub fn result(&self) -> usize {
@@ -35,22 +61,19 @@ impl Equation {
     }
 
     pub fn can_be_true_part2(&self) -> bool {
-        let mut results = vec![0];
+        let mut results = vec![0_usize];
         for number in &self.numbers {
             let mut new_results = Vec::new();
             for result in results {
-                let plus = result + number;
-                let mult = result * number;
+                let plus = result.checked_add(*number);
+                let mult = result.checked_mul(*number);
                 let conc = concat(result, *number);
-                if plus <= self.result {
-                    new_results.push(plus);
-                }
-                if mult <= self.result {
-                    new_results.push(mult);
-                }
-                if conc <= self.result {
-                    new_results.push(conc);
-                }
+                new_results.extend(
+                    [plus, mult, conc]
+                        .into_iter()
+                        .flatten()
+                        .filter(|value| *value <= self.result),
+                );
             }
             results = new_results;
         }
@@ -58,18 +81,18 @@ impl Equation {
     }
 
     pub fn can_be_true(&self) -> bool {
-        let mut results = vec![0];
+        let mut results = vec![0_usize];
         for number in &self.numbers {
             let mut new_results = Vec::new();
             for result in results {
-                let plus = result + number;
-                let mult = result * number;
-                if plus <= self.result {
-                    new_results.push(plus);
-                }
-                if mult <= self.result {
-                    new_results.push(mult);
-                }
+                let plus = result.checked_add(*number);
+                let mult = result.checked_mul(*number);
+                new_results.extend(
+                    [plus, mult]
+                        .into_iter()
+                        .flatten()
+                        .filter(|value| *value <= self.result),
+                );
             }
             results = new_results;
         }
@@ -77,8 +100,10 @@ impl Equation {
     }
 }
 
-fn concat(a: usize, b: usize) -> usize {
-    a * 10_usize.pow(b.ilog10() + 1) + b
+/// `None` on overflow, which is always above any result.
+fn concat(a: usize, b: usize) -> Option<usize> {
+    let digits = b.checked_ilog10().unwrap_or(0) + 1;
+    a.checked_mul(10_usize.checked_pow(digits)?)?.checked_add(b)
 }
 
 #[cfg(test)]
@@ -87,7 +112,10 @@ mod tests {
 
     #[test]
     fn test_concat() {
-        assert_eq!(concat(12, 34), 1234);
+        assert_eq!(concat(12, 34), Some(1234));
+        assert_eq!(concat(5, 0), Some(50));
+        assert_eq!(concat(0, 7), Some(7));
+        assert_eq!(concat(usize::MAX / 10, 99), None);
     }
 
     #[test]
@@ -99,18 +127,22 @@ mod tests {
     }
 
     #[test]
-    fn test_example() {
-        let input = "\
-            190: 10 19\n\
-            3267: 81 40 27\n\
-            83: 17 5\n\
-            156: 15 6\n\
-            7290: 6 8 6 15\n\
-            161011: 16 10 13\n\
-            192: 17 8 14\n\
-            21037: 9 7 18 13\n\
-            292: 11 6 16 20\n\
-        ";
-        assert_eq!(3749, run(input));
+    fn test_equation_parse_errors() {
+        let err = Equation::parse(" 3267: 81 4O 27").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (11, "4O"));
+        let err = Equation::parse("3267 81 40").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::Missing("':'"));
+        let err = Equation::parse("3267:").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::Missing("numbers"));
+        let err = parse("1: 1\n-2: 1 2\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: invalid number in '-2'");
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = usize::MAX;
+        let input = format!("{max}: {max} 2 10\n50: 5 0\n");
+        assert_eq!(run(&input).unwrap(), 0);
+        assert_eq!(crate::part2::run(&input).unwrap(), 50);
     }
 }
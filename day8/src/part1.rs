@@ -2,11 +2,18 @@
 
 use std::collections::{HashMap, HashSet};
 
-use crate::plane::*;
+use common::{ParseError, ParseErrorKind};
+use grid::*;
 
-pub fn run(input: &str) -> usize {
-    let plane: Plane<Spot> = Plane::parse(input);
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&Plane::parse(input)?))
+}
+
+pub fn solve(plane: &Plane<Spot>) -> usize {
+    antinodes(plane).len()
+}
 
+pub fn antinodes(plane: &Plane<Spot>) -> HashSet<Coords> {
     let mut antennas: HashMap<Spot, Vec<Coords>> =
         HashMap::with_capacity(plane.width() * plane.height());
 
@@ -14,17 +21,23 @@ pub fn run(input: &str) -> usize {
         antennas.entry(*spot).or_default().push(coords);
     }
 
-    let antinodes: HashSet<Coords> = antennas
+    antennas
         .into_values()
         .map(|v| v.into_iter())
         .flat_map(combinations)
-        .flat_map(|(c1, c2)| c1.move_by_diff(c1.diff(c2)))
-        .collect();
+        .flat_map(|(c1, c2)| step_exact(plane, c1, c1.diff(c2)))
+        .collect()
+}
 
-    antinodes
-        .into_iter()
-        .filter_map(|coord| plane.get(coord).copied())
-        .count()
+/// Moves `coords` by `diff`. A step that a clamped plane stops at its edge leaves the plane,
+/// like on a bounded one.
+pub fn step_exact<T>(plane: &Plane<T>, coords: Coords, diff: CoordDiff) -> Option<Coords> {
+    let step = plane.step_by(coords, diff)?;
+    let target = SignedCoords::from(coords).move_by_diff(diff);
+    match plane.boundary() {
+        Boundary::Clamped if SignedCoords::from(step) != target => None,
+        _ => Some(step),
+    }
 }
 
 pub fn combinations<I>(coords: I) -> impl Iterator<Item = (Coords, Coords)>
@@ -43,16 +56,27 @@ pub enum Spot {
     Empty,
 }
 
-impl From<char> for Spot {
-    fn from(c: char) -> Self {
-        match c {
-            ' ' => panic!("no spaces allowed"),
+impl TryFrom<char> for Spot {
+    type Error = ParseErrorKind;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        let spot = match c {
             '.' => Self::Empty,
             '#' => Self::Empty,
             '0'..='9' => Self::Antenna(c),
             'a'..='z' => Self::Antenna(c),
             'A'..='Z' => Self::Antenna(c),
-            _ => panic!("Unknown char {c}"),
+            _ => return Err(ParseErrorKind::UnexpectedChar),
+        };
+        Ok(spot)
+    }
+}
+
+impl ToChar for Spot {
+    fn to_char(&self) -> char {
+        match self {
+            Self::Antenna(c) => *c,
+            Self::Empty => '.',
         }
     }
 }
@@ -78,21 +102,31 @@ mod tests {
     }
 
     #[test]
-    fn test_example() {
-        let input = "\
-            ......#....#\n\
-            ...#....0...\n\
-            ....#0....#.\n\
-            ..#....0....\n\
-            ....0....#..\n\
-            .#....A.....\n\
-            ...#........\n\
-            #......#....\n\
-            ........A...\n\
-            .........A..\n\
-            ..........#.\n\
-            ..........#.\n\
-        ";
-        assert_eq!(14, run(input));
+    fn test_unknown_char() {
+        let err = run("..a.\n.. a\n").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::UnexpectedChar);
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, " "));
+    }
+
+    #[test]
+    fn test_boundaries() {
+        let plane: Plane<Spot> = Plane::parse("a...\n.a..\n....\n....\n").unwrap();
+        assert_eq!(solve(&plane), 1);
+        assert_eq!(solve(&plane.clone().with_boundary(Boundary::Clamped)), 1);
+        let wrapping = plane.with_boundary(Boundary::Wrapping);
+        assert_eq!(
+            antinodes(&wrapping),
+            HashSet::from([Coords::new(2, 2), Coords::new(3, 3)])
+        );
+    }
+
+    #[test]
+    fn test_render_antinodes() {
+        let plane = Plane::parse(include_str!("../examples/example.txt")).unwrap();
+        let antinodes = antinodes(&plane);
+        let rendered = plane.render_overlay(|coords, spot| {
+            (*spot == Spot::Empty && antinodes.contains(&coords)).then_some('#')
+        });
+        assert_eq!(rendered, include_str!("../examples/example.antinodes"));
     }
 }
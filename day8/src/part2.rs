@@ -2,12 +2,16 @@
 
 use std::collections::{HashMap, HashSet};
 
+use common::ParseError;
+use grid::*;
+
 use crate::part1::*;
-use crate::plane::*;
 
-pub fn run(input: &str) -> usize {
-    let plane: Plane<Spot> = Plane::parse(input);
+pub fn run(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&Plane::parse(input)?))
+}
 
+pub fn solve(plane: &Plane<Spot>) -> usize {
     let mut antennas: HashMap<Spot, Vec<Coords>> =
         HashMap::with_capacity(plane.width() * plane.height());
 
@@ -19,7 +23,7 @@ pub fn run(input: &str) -> usize {
         .into_values()
         .map(|v| v.into_iter())
         .flat_map(combinations)
-        .flat_map(|(c1, c2)| echo(plane.width(), plane.height(), c1, c2))
+        .flat_map(|(c1, c2)| echo(plane, c1, c2))
         .collect();
 
     antinodes
@@ -28,22 +32,18 @@ pub fn run(input: &str) -> usize {
         .count()
 }
 
-fn echo(width: usize, height: usize, c1: Coords, c2: Coords) -> impl Iterator<Item = Coords> {
+/// The antenna line from `c1` through `c2`, starting with `c2`. It ends at the edge of
+/// the plane, also of a clamped one, or when it is back at `c1` on a wrapping plane.
+fn echo<T>(plane: &Plane<T>, c1: Coords, c2: Coords) -> impl Iterator<Item = Coords> + use<'_, T> {
     let diff = c2.diff(c1);
     let mut next_coord = Some(c1);
     let next = move || -> Option<Coords> {
-        let old = next_coord?;
-        next_coord = old.move_by_diff(diff);
-        if let Some(c) = next_coord {
-            if c.x >= width || c.y >= height {
-                next_coord = None;
-            }
-        }
-        next_coord
+        let from = next_coord?;
+        let coords = step_exact(plane, from, diff).filter(|coords| *coords != from);
+        next_coord = coords.filter(|coords| *coords != c1);
+        coords
     };
-    std::iter::repeat_with(next)
-        .take_while(Option::is_some)
-        .flatten()
+    std::iter::repeat_with(next).map_while(|coords| coords)
 }
 
 #[cfg(test)]
@@ -54,7 +54,8 @@ mod tests {
     fn test_echo() {
         let c1 = Coords::new(1, 2);
         let c2 = Coords::new(2, 4);
-        let mut it = echo(6, 10, c1, c2);
+        let plane = Plane::from_cells(6, vec![Spot::Empty; 60]);
+        let mut it = echo(&plane, c1, c2);
         assert_eq!(it.next(), Some(Coords { x: 2, y: 4 }));
         assert_eq!(it.next(), Some(Coords { x: 3, y: 6 }));
         assert_eq!(it.next(), Some(Coords { x: 4, y: 8 }));
@@ -62,21 +63,25 @@ mod tests {
     }
 
     #[test]
-    fn test_example() {
-        let input = "\
-            ......#....#\n\
-            ...#....0...\n\
-            ....#0....#.\n\
-            ..#....0....\n\
-            ....0....#..\n\
-            .#....A.....\n\
-            ...#........\n\
-            #......#....\n\
-            ........A...\n\
-            .........A..\n\
-            ..........#.\n\
-            ..........#.\n\
-        ";
-        assert_eq!(34, run(input));
+    fn test_echo_wrapping() {
+        let c1 = Coords::new(1, 2);
+        let c2 = Coords::new(2, 4);
+        let plane = Plane::from_cells(6, vec![Spot::Empty; 60]).with_boundary(Boundary::Wrapping);
+        let line: Vec<_> = echo(&plane, c1, c2).collect();
+        assert_eq!(line[3], Coords { x: 5, y: 0 });
+        assert_eq!(line.len(), 30);
+        assert_eq!(line.last(), Some(&c1));
+    }
+
+    #[test]
+    fn test_boundaries() {
+        let plane: Plane<Spot> = Plane::parse("a...\n.a..\n....\n....\n").unwrap();
+        assert_eq!(solve(&plane), 4);
+        for boundary in [Boundary::Clamped, Boundary::Wrapping] {
+            assert_eq!(solve(&plane.clone().with_boundary(boundary)), 4);
+        }
+        let plane = plane.with_boundary(Boundary::Clamped);
+        let line: Vec<_> = echo(&plane, Coords::new(1, 1), Coords::new(0, 0)).collect();
+        assert_eq!(line, [Coords::new(0, 0)]);
     }
 }
@@ -0,0 +1,118 @@
+use crate::{CoordDiff, Coords, Direction, Plane, SignedCoords};
+
+/// What happens when a step leaves a [`Plane`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Boundary {
+    /// The step leaves the plane.
+    #[default]
+    Bounded,
+    /// The step enters the plane again on the opposite edge, like on a torus.
+    Wrapping,
+    /// The step stops at the edge.
+    Clamped,
+}
+
+impl<T> Plane<T> {
+    /// Moves one cell into `direction`, following the [`Boundary`] of the plane.
+    /// `None` if the step leaves a [`Bounded`](Boundary::Bounded) plane.
+    pub fn step(&self, coords: Coords, direction: impl Into<Direction>) -> Option<Coords> {
+        self.step_by(coords, direction.into().offset())
+    }
+
+    /// Moves by `diff`, following the [`Boundary`] of the plane.
+    pub fn step_by(&self, coords: Coords, diff: CoordDiff) -> Option<Coords> {
+        self.place(SignedCoords::from(coords).move_by_diff(diff))
+    }
+
+    /// Maps coords that may be outside of the plane into it, following its [`Boundary`].
+    pub fn place(&self, coords: SignedCoords) -> Option<Coords> {
+        self.place_with(coords, self.boundary())
+    }
+
+    pub(crate) fn place_with(&self, coords: SignedCoords, boundary: Boundary) -> Option<Coords> {
+        if self.cells().is_empty() {
+            return None;
+        }
+        let width = self.width() as isize;
+        let height = self.height() as isize;
+        let (x, y) = match boundary {
+            Boundary::Bounded => {
+                let coords = Coords::try_from(coords).ok()?;
+                return self.index(coords).is_some().then_some(coords);
+            }
+            Boundary::Wrapping => (coords.x.rem_euclid(width), coords.y.rem_euclid(height)),
+            Boundary::Clamped => (coords.x.clamp(0, width - 1), coords.y.clamp(0, height - 1)),
+        };
+        Some(Coords::new(x as usize, y as usize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Connectivity;
+
+    fn plane(boundary: Boundary) -> Plane<char> {
+        Plane::parse("abc\n123").unwrap().with_boundary(boundary)
+    }
+
+    #[test]
+    fn test_bounded() {
+        let plane = plane(Boundary::Bounded);
+        assert_eq!(plane.step(Coords::new(0, 0), Direction::Up), None);
+        assert_eq!(
+            plane.step(Coords::new(0, 0), Direction::DownRight),
+            Some(Coords::new(1, 1))
+        );
+        assert_eq!(plane.step(Coords::new(2, 1), Direction::Right), None);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let plane = plane(Boundary::Wrapping);
+        assert_eq!(
+            plane.step(Coords::new(0, 0), Direction::UpLeft),
+            Some(Coords::new(2, 1))
+        );
+        let diff = CoordDiff { x: 7, y: -3 };
+        assert_eq!(
+            plane.step_by(Coords::new(1, 0), diff),
+            Some(Coords::new(2, 1))
+        );
+        let neighbours: Vec<_> = plane
+            .neighbours(Coords::new(0, 0), Connectivity::Four)
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(neighbours, ['1', 'b', 'c']);
+
+        let plane = Plane::<char>::parse("ab\ncd")
+            .unwrap()
+            .with_boundary(Boundary::Wrapping);
+        let neighbours: Vec<_> = plane
+            .neighbours(Coords::new(0, 0), Connectivity::Eight)
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(neighbours, ['c', 'd', 'b']);
+    }
+
+    #[test]
+    fn test_clamped() {
+        let plane = plane(Boundary::Clamped);
+        assert_eq!(
+            plane.step(Coords::new(0, 0), Direction::Up),
+            Some(Coords::new(0, 0))
+        );
+        let diff = CoordDiff { x: 7, y: -3 };
+        assert_eq!(
+            plane.step_by(Coords::new(1, 1), diff),
+            Some(Coords::new(2, 0))
+        );
+        assert_eq!(
+            plane
+                .neighbours(Coords::new(0, 0), Connectivity::Eight)
+                .count(),
+            3
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use crate::{Connectivity, Direction};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoordDiff {
+    pub x: isize,
+    pub y: isize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Coords {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl From<(usize, usize)> for Coords {
+    fn from(value: (usize, usize)) -> Self {
+        Self::new(value.0, value.1)
+    }
+}
+
+impl Coords {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn diff(self, other: Self) -> CoordDiff {
+        let x: isize = self.x.try_into().unwrap();
+        let y: isize = self.y.try_into().unwrap();
+        let other_x: isize = other.x.try_into().unwrap();
+        let other_y: isize = other.y.try_into().unwrap();
+        CoordDiff {
+            x: x - other_x,
+            y: y - other_y,
+        }
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> u64 {
+        (self.x.abs_diff(other.x) + self.y.abs_diff(other.y)) as u64
+    }
+
+    pub fn move_by_diff(self, diff: CoordDiff) -> Option<Self> {
+        let x: isize = isize::try_from(self.x).unwrap() + diff.x;
+        let y: isize = isize::try_from(self.y).unwrap() + diff.y;
+
+        let new = Self {
+            x: x.try_into().ok()?,
+            y: y.try_into().ok()?,
+        };
+        Some(new)
+    }
+
+    fn move_up(self) -> Option<Self> {
+        Some(Self {
+            x: self.x,
+            y: self.y.checked_sub(1)?,
+        })
+    }
+
+    fn move_down(self) -> Option<Self> {
+        Some(Self {
+            x: self.x,
+            y: self.y.checked_add(1)?,
+        })
+    }
+
+    fn move_left(self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(1)?,
+            y: self.y,
+        })
+    }
+
+    fn move_right(self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(1)?,
+            y: self.y,
+        })
+    }
+
+    /// The neighbours in the directions of `connectivity` that don't leave the
+    /// non-negative quadrant.
+    pub fn neighbours(self, connectivity: Connectivity) -> impl Iterator<Item = Self> {
+        connectivity
+            .directions()
+            .iter()
+            .filter_map(move |direction| self.move_into_direction(*direction))
+    }
+
+    pub fn move_into_direction(&self, direction: impl Into<Direction>) -> Option<Self> {
+        match direction.into() {
+            Direction::Up => self.move_up(),
+            Direction::UpRight => self.move_up()?.move_right(),
+            Direction::Right => self.move_right(),
+            Direction::DownRight => self.move_down()?.move_right(),
+            Direction::Down => self.move_down(),
+            Direction::DownLeft => self.move_down()?.move_left(),
+            Direction::Left => self.move_left(),
+            Direction::UpLeft => self.move_up()?.move_left(),
+        }
+    }
+}
+
+/// Coords that can leave the non-negative quadrant, e.g. for a [`SparsePlane`](crate::SparsePlane).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignedCoords {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl From<(isize, isize)> for SignedCoords {
+    fn from(value: (isize, isize)) -> Self {
+        Self::new(value.0, value.1)
+    }
+}
+
+impl From<Coords> for SignedCoords {
+    fn from(coords: Coords) -> Self {
+        Self::new(coords.x.try_into().unwrap(), coords.y.try_into().unwrap())
+    }
+}
+
+impl TryFrom<SignedCoords> for Coords {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(coords: SignedCoords) -> Result<Self, Self::Error> {
+        Ok(Self::new(coords.x.try_into()?, coords.y.try_into()?))
+    }
+}
+
+impl SignedCoords {
+    pub fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn diff(self, other: Self) -> CoordDiff {
+        CoordDiff {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+
+    pub fn move_by_diff(self, diff: CoordDiff) -> Self {
+        Self::new(self.x + diff.x, self.y + diff.y)
+    }
+
+    pub fn move_into_direction(self, direction: impl Into<Direction>) -> Self {
+        self.move_by_diff(direction.into().offset())
+    }
+
+    pub fn neighbours(self, connectivity: Connectivity) -> impl Iterator<Item = Self> {
+        connectivity
+            .directions()
+            .iter()
+            .map(move |direction| self.move_into_direction(*direction))
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> u64 {
+        (self.x.abs_diff(other.x) + self.y.abs_diff(other.y)) as u64
+    }
+}
+
+/// Smallest rectangle containing some coords, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bounds {
+    pub min: Coords,
+    pub max: Coords,
+}
+
+impl Bounds {
+    pub fn width(&self) -> usize {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.max.y - self.min.y + 1
+    }
+
+    pub fn contains(&self, coords: Coords) -> bool {
+        (self.min.x..=self.max.x).contains(&coords.x)
+            && (self.min.y..=self.max.y).contains(&coords.y)
+    }
+
+    pub(crate) fn extend(&mut self, coords: Coords) {
+        self.min = Coords::new(self.min.x.min(coords.x), self.min.y.min(coords.y));
+        self.max = Coords::new(self.max.x.max(coords.x), self.max.y.max(coords.y));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let c1 = Coords::new(4, 2);
+        let c2 = Coords::new(2, 1);
+        let diff = c1.diff(c2);
+        assert_eq!(diff.x, 2);
+        assert_eq!(diff.y, 1);
+        assert_eq!(c2.move_by_diff(diff), Some(c1));
+    }
+
+    #[test]
+    fn test_move_diff_out_of_bounds() {
+        let c1 = Coords::new(4, 2);
+        let c2 = Coords::new(2, 1);
+        let diff = c2.diff(c1);
+        assert_eq!(diff.x, -2);
+        assert_eq!(diff.y, -1);
+        assert_eq!(c2.move_by_diff(diff), Some(Coords::new(0, 0)));
+        assert_eq!(Coords::new(1, 0).move_by_diff(diff), None);
+    }
+
+    #[test]
+    fn test_move_into_direction() {
+        let coords = Coords::new(1, 0);
+        assert_eq!(coords.move_into_direction(Direction::Up), None);
+        assert_eq!(
+            coords.move_into_direction(Direction::DownLeft),
+            Some(Coords::new(0, 1))
+        );
+        assert_eq!(
+            coords.move_into_direction(Direction::Right),
+            Some(Coords::new(2, 0))
+        );
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        assert_eq!(Coords::new(4, 1).manhattan_distance(Coords::new(1, 3)), 5);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let neighbours: Vec<_> = Coords::new(0, 1).neighbours(Connectivity::Four).collect();
+        assert_eq!(
+            neighbours,
+            [Coords::new(0, 0), Coords::new(1, 1), Coords::new(0, 2)]
+        );
+        assert_eq!(Coords::new(0, 0).neighbours(Connectivity::Eight).count(), 3);
+        assert_eq!(Coords::new(1, 1).neighbours(Connectivity::Eight).count(), 8);
+    }
+
+    #[test]
+    fn test_signed_coords() {
+        let origin = SignedCoords::new(0, 0);
+        let up_left = origin.move_into_direction(Direction::UpLeft);
+        assert_eq!(up_left, SignedCoords::new(-1, -1));
+        assert_eq!(Coords::try_from(up_left).ok(), None);
+        assert_eq!(Coords::try_from(origin), Ok(Coords::new(0, 0)));
+        assert_eq!(origin.neighbours(Connectivity::Eight).count(), 8);
+        assert_eq!(up_left.diff(origin), CoordDiff { x: -1, y: -1 });
+        assert_eq!(up_left.manhattan_distance(SignedCoords::new(2, 0)), 4);
+    }
+}
@@ -0,0 +1,331 @@
+use crate::CoordDiff;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// All directions, clockwise starting with [`Up`](Self::Up).
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Up, right, down and left, clockwise.
+    pub const CARDINAL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// The four diagonals, clockwise starting with [`UpRight`](Self::UpRight).
+    pub const DIAGONAL: [Self; 4] = [Self::UpRight, Self::DownRight, Self::DownLeft, Self::UpLeft];
+
+    pub fn is_cardinal(&self) -> bool {
+        matches!(self, Self::Up | Self::Right | Self::Down | Self::Left)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        !self.is_cardinal()
+    }
+
+    /// Clockwise angle from [`Up`](Self::Up) in degrees, in `0..360`.
+    pub fn angle(&self) -> i32 {
+        *self as i32 * 45
+    }
+
+    /// `None` if `degrees` is not a multiple of 45°.
+    pub fn from_angle(degrees: i32) -> Option<Self> {
+        (degrees % 45 == 0).then(|| Self::ALL[(degrees / 45).rem_euclid(8) as usize])
+    }
+
+    /// Turns clockwise by `degrees`, counter-clockwise if negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `degrees` is not a multiple of 45°.
+    pub fn rotate(&self, degrees: i32) -> Self {
+        match Self::from_angle(self.angle() + degrees.rem_euclid(360)) {
+            Some(direction) => direction,
+            None => panic!("cannot rotate a direction by {degrees}°"),
+        }
+    }
+
+    /// The step of one cell into this direction, y grows downwards.
+    pub fn offset(&self) -> CoordDiff {
+        let (x, y) = match self {
+            Self::Up => (0, -1),
+            Self::UpRight => (1, -1),
+            Self::Right => (1, 0),
+            Self::DownRight => (1, 1),
+            Self::Down => (0, 1),
+            Self::DownLeft => (-1, 1),
+            Self::Left => (-1, 0),
+            Self::UpLeft => (-1, -1),
+        };
+        CoordDiff { x, y }
+    }
+
+    /// The direction of a unit step, `None` for any other diff.
+    pub fn from_offset(diff: CoordDiff) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|direction| direction.offset() == diff)
+    }
+
+    pub fn is_opposite(&self, other: Direction) -> bool {
+        self.opposite() == other
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate(180)
+    }
+
+    /// Turns 45° counter-clockwise.
+    pub fn rot_left(&self) -> Self {
+        self.rotate(-45)
+    }
+
+    /// Turns 45° clockwise.
+    pub fn rot_right(&self) -> Self {
+        self.rotate(45)
+    }
+
+    /// Turns 90° counter-clockwise.
+    pub fn turn_left(&self) -> Self {
+        self.rotate(-90)
+    }
+
+    /// Turns 90° clockwise.
+    pub fn turn_right(&self) -> Self {
+        self.rotate(90)
+    }
+}
+
+impl From<Direction> for CoordDiff {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+impl TryFrom<CoordDiff> for Direction {
+    type Error = CoordDiff;
+
+    fn try_from(diff: CoordDiff) -> Result<Self, Self::Error> {
+        Self::from_offset(diff).ok_or(diff)
+    }
+}
+
+/// Generates a subset of [`Direction`] that is closed under turns by 90°.
+macro_rules! direction_subset {
+    ($(#[$meta:meta])* $name:ident: $($variant:ident),+) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum $name {
+            $($variant),+
+        }
+
+        impl $name {
+            /// Clockwise.
+            pub const ALL: [Self; 4] = [$(Self::$variant),+];
+
+            /// Turns clockwise by `degrees`, counter-clockwise if negative.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `degrees` is not a multiple of 90°.
+            pub fn rotate(&self, degrees: i32) -> Self {
+                assert_eq!(degrees % 90, 0, "cannot rotate a {} by {degrees}°", stringify!($name));
+                Direction::from(*self)
+                    .rotate(degrees.rem_euclid(360))
+                    .try_into()
+                    .unwrap()
+            }
+
+            pub fn opposite(&self) -> Self {
+                self.rotate(180)
+            }
+
+            /// Turns 90° counter-clockwise.
+            pub fn turn_left(&self) -> Self {
+                self.rotate(-90)
+            }
+
+            /// Turns 90° clockwise.
+            pub fn turn_right(&self) -> Self {
+                self.rotate(90)
+            }
+
+            pub fn offset(&self) -> CoordDiff {
+                Direction::from(*self).offset()
+            }
+        }
+
+        impl From<$name> for Direction {
+            fn from(direction: $name) -> Self {
+                match direction {
+                    $($name::$variant => Self::$variant),+
+                }
+            }
+        }
+
+        impl TryFrom<Direction> for $name {
+            type Error = Direction;
+
+            fn try_from(direction: Direction) -> Result<Self, Self::Error> {
+                match direction {
+                    $(Direction::$variant => Ok(Self::$variant),)+
+                    _ => Err(direction),
+                }
+            }
+        }
+    };
+}
+
+direction_subset!(
+    /// A direction along an axis.
+    Cardinal: Up, Right, Down, Left
+);
+
+direction_subset!(
+    /// A direction between two axes.
+    Diagonal: UpRight, DownRight, DownLeft, UpLeft
+);
+
+/// Which cells count as neighbours of a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// Only the cells sharing an edge.
+    Four,
+    /// The cells sharing an edge or a corner.
+    Eight,
+}
+
+impl Connectivity {
+    pub fn directions(self) -> &'static [Direction] {
+        match self {
+            Self::Four => &Direction::CARDINAL,
+            Self::Eight => &Direction::ALL,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn test_rotation() {
+        for direction in Direction::iter() {
+            assert_eq!(direction.rot_left().rot_right(), direction);
+            assert!(
+                direction.is_opposite(direction.rot_right().rot_right().rot_right().rot_right())
+            );
+        }
+        assert_eq!(Direction::Up.rot_right(), Direction::UpRight);
+        assert_eq!(Direction::Up.opposite(), Direction::Down);
+        assert_eq!(Direction::DownLeft.offset(), CoordDiff { x: -1, y: 1 });
+    }
+
+    #[test]
+    fn test_sets() {
+        assert_eq!(
+            Direction::ALL.to_vec(),
+            Direction::iter().collect::<Vec<_>>()
+        );
+        assert!(Direction::CARDINAL.iter().all(Direction::is_cardinal));
+        assert!(Direction::DIAGONAL.iter().all(Direction::is_diagonal));
+        assert_eq!(Connectivity::Four.directions(), Direction::CARDINAL);
+        assert_eq!(Connectivity::Eight.directions().len(), 8);
+    }
+
+    #[test]
+    fn test_angles() {
+        assert_eq!(Direction::Left.angle(), 270);
+        assert_eq!(Direction::from_angle(-45), Some(Direction::UpLeft));
+        assert_eq!(Direction::from_angle(30), None);
+        assert_eq!(Direction::Up.rotate(540), Direction::Down);
+        assert_eq!(Direction::UpRight.turn_right(), Direction::DownRight);
+        assert_eq!(Direction::UpRight.turn_left(), Direction::UpLeft);
+
+        let big = i32::MAX - i32::MAX % 45;
+        assert_eq!(
+            Direction::Up.rotate(big),
+            Direction::from_angle(big).unwrap()
+        );
+        assert_eq!(
+            Direction::Left.rotate(-big),
+            Direction::Left.rotate(-(big % 360))
+        );
+        let big = i32::MAX - i32::MAX % 90;
+        assert_eq!(Cardinal::Up.rotate(big), Cardinal::Up.rotate(big % 360));
+        assert_eq!(
+            Diagonal::UpLeft.rotate(-big),
+            Diagonal::UpLeft.rotate(-(big % 360))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot rotate a direction by 10°")]
+    fn test_rotate_panics() {
+        Direction::Up.rotate(10);
+    }
+
+    #[test]
+    fn test_offsets() {
+        for direction in Direction::ALL {
+            assert_eq!(
+                Direction::try_from(CoordDiff::from(direction)),
+                Ok(direction)
+            );
+        }
+        let diff = CoordDiff { x: 2, y: 0 };
+        assert_eq!(Direction::try_from(diff), Err(diff));
+    }
+
+    #[test]
+    fn test_subsets() {
+        assert_eq!(Cardinal::Up.turn_right(), Cardinal::Right);
+        assert_eq!(Cardinal::Left.turn_right(), Cardinal::Up);
+        assert_eq!(Cardinal::Up.opposite(), Cardinal::Down);
+        assert_eq!(Diagonal::UpLeft.turn_right(), Diagonal::UpRight);
+        assert_eq!(Diagonal::DownRight.rotate(-270), Diagonal::DownLeft);
+        assert_eq!(Cardinal::try_from(Direction::Down), Ok(Cardinal::Down));
+        assert_eq!(
+            Cardinal::try_from(Direction::DownLeft),
+            Err(Direction::DownLeft)
+        );
+        assert_eq!(
+            Diagonal::try_from(Direction::DownLeft),
+            Ok(Diagonal::DownLeft)
+        );
+        let cardinal: Vec<_> = Cardinal::ALL.map(Direction::from).to_vec();
+        assert_eq!(cardinal, Direction::CARDINAL);
+        assert_eq!(Diagonal::ALL.map(Direction::from), Direction::DIAGONAL);
+        assert_eq!(Cardinal::Left.offset(), CoordDiff { x: -1, y: 0 });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let json = serde_json::to_string(&(Direction::UpLeft, Cardinal::Down)).unwrap();
+        assert_eq!(json, r#"["UpLeft","Down"]"#);
+        let back: (Direction, Cardinal) = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, (Direction::UpLeft, Cardinal::Down));
+        assert!(serde_json::from_str::<Cardinal>(r#""UpLeft""#).is_err());
+    }
+}
@@ -0,0 +1,290 @@
+use std::fmt;
+
+use common::parse::{self, ParseError, ParseErrorKind};
+
+use crate::{Coords, Plane, SignedCoords, ToChar};
+
+/// Axial coords on a hex grid with pointy tops, `r` is the row and `q` grows to the east.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HexCoords {
+    pub q: isize,
+    pub r: isize,
+}
+
+impl HexCoords {
+    pub fn new(q: isize, r: isize) -> Self {
+        Self { q, r }
+    }
+
+    /// Cube coords `(q, r, s)`, which add up to zero.
+    pub fn cube(self) -> (isize, isize, isize) {
+        (self.q, self.r, -self.q - self.r)
+    }
+
+    pub fn from_cube(q: isize, r: isize, s: isize) -> Option<Self> {
+        (q + r + s == 0).then_some(Self { q, r })
+    }
+
+    pub fn move_into_direction(self, direction: HexDirection) -> Self {
+        let (dq, dr) = direction.offset();
+        Self::new(self.q + dq, self.r + dr)
+    }
+
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        HexDirection::ALL
+            .into_iter()
+            .map(move |direction| self.move_into_direction(direction))
+    }
+
+    /// Number of steps between the two cells.
+    pub fn distance(self, other: Self) -> u64 {
+        let (q, r, s) = self.cube();
+        let (other_q, other_r, other_s) = other.cube();
+        (q.abs_diff(other_q) + r.abs_diff(other_r) + s.abs_diff(other_s)) as u64 / 2
+    }
+}
+
+/// The six neighbours on a hex grid with pointy tops, clockwise starting with
+/// [`East`](Self::East).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HexDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl HexDirection {
+    pub const ALL: [Self; 6] = [
+        Self::East,
+        Self::SouthEast,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+        Self::NorthEast,
+    ];
+
+    /// The step `(dq, dr)` in axial coords.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Self::East => (1, 0),
+            Self::SouthEast => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (0, -1),
+            Self::NorthEast => (1, -1),
+        }
+    }
+
+    /// Turns by `steps` times 60°, clockwise for positive steps.
+    pub fn rotate(self, steps: i32) -> Self {
+        let index = Self::ALL.iter().position(|d| *d == self).unwrap() as i32;
+        Self::ALL[(index + steps).rem_euclid(6) as usize]
+    }
+
+    pub fn rot_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    pub fn rot_left(self) -> Self {
+        self.rotate(-1)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(3)
+    }
+}
+
+/// A rectangular hex grid with pointy tops, where every odd row is shifted half a cell to
+/// the east. In text, the cells of a row are separated by whitespace:
+///
+/// ```text
+/// a b c
+///  d e f
+/// g h i
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HexGrid<T> {
+    plane: Plane<T>,
+}
+
+impl<T> HexGrid<T>
+where
+    T: TryFrom<char>,
+    ParseErrorKind: From<T::Error>,
+{
+    /// Whitespace is ignored and blank lines are skipped, every row must have as many cells
+    /// as the first one.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        for (line_no, line) in parse::lines(input) {
+            let row: Vec<_> = line.match_indices(|c: char| !c.is_whitespace()).collect();
+            if row.is_empty() {
+                continue;
+            }
+            let expected = *width.get_or_insert(row.len());
+            if row.len() != expected {
+                let kind = ParseErrorKind::RowWidth {
+                    expected,
+                    found: row.len(),
+                };
+                let text = match row.get(expected) {
+                    Some((i, _)) => &line[*i..],
+                    None => &line[line.trim_end().len()..],
+                };
+                return Err(ParseError::new(kind, line, text).on_line(line_no));
+            }
+            for (i, text) in row {
+                let c = text.chars().next().unwrap();
+                let t = T::try_from(c).map_err(|err| {
+                    ParseError::new(err.into(), line, &line[i..i + text.len()]).on_line(line_no)
+                })?;
+                cells.push(t);
+            }
+        }
+        Ok(Self {
+            plane: Plane::from_cells(width.unwrap_or(0), cells),
+        })
+    }
+}
+
+impl<T> HexGrid<T> {
+    pub fn width(&self) -> usize {
+        self.plane.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.plane.height()
+    }
+
+    /// The cells by row and column, as they are written in text.
+    pub fn as_plane(&self) -> &Plane<T> {
+        &self.plane
+    }
+
+    /// Row and column of `coords`.
+    pub fn to_offset(coords: HexCoords) -> Option<Coords> {
+        let x = coords.q + (coords.r - (coords.r & 1)) / 2;
+        Coords::try_from(SignedCoords::new(x, coords.r)).ok()
+    }
+
+    /// Hex coords of a cell given by row and column.
+    pub fn from_offset(coords: Coords) -> HexCoords {
+        let (x, y) = (coords.x as isize, coords.y as isize);
+        HexCoords::new(x - (y - (y & 1)) / 2, y)
+    }
+
+    pub fn get(&self, coords: HexCoords) -> Option<&T> {
+        self.plane.get(Self::to_offset(coords)?)
+    }
+
+    pub fn get_mut(&mut self, coords: HexCoords) -> Option<&mut T> {
+        self.plane.get_mut(Self::to_offset(coords)?)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (HexCoords, &T)> {
+        self.plane
+            .iter()
+            .map(|(coords, t)| (Self::from_offset(coords), t))
+    }
+
+    /// The neighbours of `coords` inside the grid, with their cells.
+    pub fn neighbours(&self, coords: HexCoords) -> impl Iterator<Item = (HexCoords, &T)> {
+        coords
+            .neighbours()
+            .filter_map(|coords| Some((coords, self.get(coords)?)))
+    }
+
+    /// Draws every cell with `cell`, in the layout that [`parse`](Self::parse) reads.
+    pub fn render(&self, mut cell: impl FnMut(HexCoords, &T) -> char) -> String {
+        let mut out = String::new();
+        for (coords, t) in self.plane.iter() {
+            match coords.x {
+                0 if coords.y % 2 == 1 => out.push(' '),
+                0 => {}
+                _ => out.push(' '),
+            }
+            out.push(cell(Self::from_offset(coords), t));
+            if coords.x + 1 == self.width() {
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+impl<T: ToChar> fmt::Display for HexGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|_, t| t.to_char()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID: &str = "\
+        a b c\n \
+        d e f\n\
+        g h i\n\
+    ";
+
+    #[test]
+    fn test_directions() {
+        let origin = HexCoords::new(0, 0);
+        for direction in HexDirection::ALL {
+            let there = origin.move_into_direction(direction);
+            assert_eq!(origin.distance(there), 1);
+            assert_eq!(there.move_into_direction(direction.opposite()), origin);
+            assert_eq!(direction.rot_right().rot_left(), direction);
+        }
+        assert_eq!(HexDirection::East.rotate(-2), HexDirection::NorthWest);
+        assert_eq!(HexDirection::NorthEast.rot_right(), HexDirection::East);
+    }
+
+    #[test]
+    fn test_distance() {
+        let a = HexCoords::new(1, -3);
+        assert_eq!(HexCoords::from_cube(1, -3, 2), Some(a));
+        assert_eq!(HexCoords::from_cube(1, 1, 1), None);
+        assert_eq!(a.distance(HexCoords::new(-2, 0)), 3);
+        assert_eq!(a.distance(HexCoords::new(3, 1)), 6);
+    }
+
+    #[test]
+    fn test_parse_and_render() {
+        let grid: HexGrid<char> = HexGrid::parse(GRID).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.to_string(), "a b c\n d e f\ng h i\n");
+        let rendered = grid.render(|coords, c| if coords.q == 0 { '*' } else { *c });
+        assert_eq!(rendered, "* b c\n * e f\ng * i\n");
+
+        let err = HexGrid::<char>::parse("a b\n c d e\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 6, "e"));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid: HexGrid<char> = HexGrid::parse(GRID).unwrap();
+        let e = HexGrid::<char>::from_offset(Coords::new(1, 1));
+        assert_eq!(grid.get(e), Some(&'e'));
+        let mut neighbours: Vec<_> = grid.neighbours(e).map(|(_, c)| *c).collect();
+        neighbours.sort();
+        assert_eq!(neighbours, ['b', 'c', 'd', 'f', 'h', 'i']);
+        let a = HexCoords::new(0, 0);
+        let mut neighbours: Vec<_> = grid.neighbours(a).map(|(_, c)| *c).collect();
+        neighbours.sort();
+        assert_eq!(neighbours, ['b', 'd']);
+        for (coords, _) in grid.iter() {
+            assert_eq!(
+                HexGrid::<char>::from_offset(HexGrid::<char>::to_offset(coords).unwrap()),
+                coords
+            );
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Grids of puzzle inputs, mostly two dimensional.
+
+mod boundary;
+mod coords;
+mod direction;
+mod hex;
+mod nd;
+mod plane;
+mod regions;
+mod render;
+mod search;
+mod sparse;
+mod transform;
+
+pub use boundary::Boundary;
+pub use coords::{Bounds, CoordDiff, Coords, SignedCoords};
+pub use direction::{Cardinal, Connectivity, Diagonal, Direction};
+pub use hex::{HexCoords, HexDirection, HexGrid};
+pub use nd::{Grid, Point};
+pub use plane::{BlankLines, ParseOptions, Plane};
+pub use regions::{ComponentId, Components, Region};
+pub use render::ToChar;
+pub use search::Path;
+pub use sparse::SparsePlane;
+pub use transform::View;
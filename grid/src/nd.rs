@@ -0,0 +1,423 @@
+use std::ops::{Index, IndexMut};
+
+use common::parse::{self, ParseError, ParseErrorKind};
+
+use crate::{Connectivity, Coords, Plane};
+
+/// Coords in `N` dimensions, the first one grows fastest in the cells of a [`Grid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<const N: usize>(pub [usize; N]);
+
+impl From<Coords> for Point<2> {
+    fn from(coords: Coords) -> Self {
+        Self([coords.x, coords.y])
+    }
+}
+
+impl From<Point<2>> for Coords {
+    fn from(Point([x, y]): Point<2>) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<const N: usize> Point<N> {
+    pub fn new(coords: [usize; N]) -> Self {
+        Self(coords)
+    }
+
+    /// The unit steps to the neighbours. [`Connectivity::Four`] only steps along one axis,
+    /// which gives `2 * N` neighbours, [`Connectivity::Eight`] steps along any axes and
+    /// gives `3^N - 1` neighbours.
+    pub fn offsets(connectivity: Connectivity) -> Vec<[isize; N]> {
+        match connectivity {
+            Connectivity::Four => (0..N)
+                .flat_map(|axis| {
+                    [-1, 1].map(|step| {
+                        let mut offset = [0; N];
+                        offset[axis] = step;
+                        offset
+                    })
+                })
+                .collect(),
+            Connectivity::Eight => {
+                let mut offsets = vec![[0; N]];
+                for axis in 0..N {
+                    offsets = offsets
+                        .into_iter()
+                        .flat_map(|offset| {
+                            [-1, 0, 1].map(|step| {
+                                let mut offset = offset;
+                                offset[axis] = step;
+                                offset
+                            })
+                        })
+                        .collect();
+                }
+                offsets.retain(|offset| offset.iter().any(|step| *step != 0));
+                offsets
+            }
+        }
+    }
+
+    /// `None` if a coordinate would become negative.
+    pub fn move_by(self, offset: [isize; N]) -> Option<Self> {
+        let mut moved = self.0;
+        for (coord, step) in moved.iter_mut().zip(offset) {
+            *coord = coord.checked_add_signed(step)?;
+        }
+        Some(Self(moved))
+    }
+
+    /// The neighbours that don't leave the non-negative coordinates.
+    pub fn neighbours(self, connectivity: Connectivity) -> impl Iterator<Item = Self> {
+        Self::offsets(connectivity)
+            .into_iter()
+            .filter_map(move |offset| self.move_by(offset))
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> u64 {
+        self.0
+            .iter()
+            .zip(other.0)
+            .map(|(a, b)| a.abs_diff(b) as u64)
+            .sum()
+    }
+}
+
+/// A grid in `N` dimensions, stored in one `Vec` with the first dimension growing
+/// fastest. [`Plane`] is the two dimensional case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T, const N: usize> {
+    cells: Vec<T>,
+    dims: [usize; N],
+}
+
+impl<T, const N: usize> Grid<T, N>
+where
+    T: TryFrom<char>,
+    ParseErrorKind: From<T::Error>,
+{
+    /// Parses two dimensional layers like [`Plane::parse`]. A blank line separates the
+    /// layers along the third dimension, two blank lines separate blocks of layers along
+    /// the fourth one and so on, up to `N - 2` blank lines between blocks along the last
+    /// dimension. Longer runs count as that longest separator. Every layer must have the size
+    /// of the first one, and every block as many layers as the first one. A two dimensional
+    /// grid skips blank lines like a [`Plane`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is less than two.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        assert!(N >= 2, "a grid to parse needs at least two dimensions");
+        if N == 2 {
+            let plane = Plane::parse(input)?;
+            let mut dims = [0; N];
+            dims[0] = plane.width();
+            dims[1] = plane.height();
+            return Ok(Self::from_cells(dims, Grid::from(plane).into_cells()));
+        }
+
+        let mut layers = split_layers(input).peekable();
+        let mut dims = [None; N];
+        // position of the next layer along every dimension from the third one on
+        let mut position = [0; N];
+        let mut cells = Vec::with_capacity(input.len());
+        while let Some(layer) = layers.next() {
+            let plane = Plane::<T>::parse(&layer.text).map_err(|err| {
+                let line = err.line + layer.first_line - 1;
+                err.on_line(line)
+            })?;
+            let size = [plane.width(), plane.height()];
+            let first_size = [
+                *dims[0].get_or_insert(size[0]),
+                *dims[1].get_or_insert(size[1]),
+            ];
+            if size != first_size {
+                let kind = ParseErrorKind::Invalid("layer, its size differs from the first one");
+                return Err(ParseError::new(kind, layer.first, layer.first.trim())
+                    .on_line(layer.first_line));
+            }
+            cells.extend(Grid::from(plane).into_cells());
+
+            // the separator after the last layer closes every dimension
+            let closed = match layers.peek() {
+                Some(next) => next.blank_before.min(N - 2),
+                None => N - 1,
+            };
+            position[2] += 1;
+            for d in 2..=closed {
+                if *dims[d].get_or_insert(position[d]) != position[d] {
+                    let kind = ParseErrorKind::Invalid(
+                        "block of layers, it has another size than the first one",
+                    );
+                    let end = &layer.last[layer.last.len()..];
+                    return Err(ParseError::new(kind, layer.last, end).on_line(layer.last_line));
+                }
+                position[d] = 0;
+                if d + 1 < N {
+                    position[d + 1] += 1;
+                }
+            }
+        }
+        Ok(Self::from_cells(dims.map(|dim| dim.unwrap_or(0)), cells))
+    }
+}
+
+/// Lines between blank lines, see [`Grid::parse`].
+struct Layer<'a> {
+    text: String,
+    first: &'a str,
+    first_line: usize,
+    last: &'a str,
+    last_line: usize,
+    /// number of blank lines between this layer and the one before
+    blank_before: usize,
+}
+
+fn split_layers(input: &str) -> impl Iterator<Item = Layer<'_>> {
+    let mut lines = parse::lines(input).peekable();
+    std::iter::from_fn(move || {
+        let mut blank_before = 0;
+        while lines.next_if(|(_, line)| line.trim().is_empty()).is_some() {
+            blank_before += 1;
+        }
+        let (first_line, first) = lines.next()?;
+        let mut layer = Layer {
+            text: first.to_owned(),
+            first,
+            first_line,
+            last: first,
+            last_line: first_line,
+            blank_before,
+        };
+        while let Some((line_no, line)) = lines.next_if(|(_, line)| !line.trim().is_empty()) {
+            layer.text.push('\n');
+            layer.text.push_str(line);
+            layer.last = line;
+            layer.last_line = line_no;
+        }
+        Some(layer)
+    })
+}
+
+impl<T: Clone, const N: usize> Grid<T, N> {
+    pub fn filled(dims: [usize; N], value: T) -> Self {
+        Self::from_cells(dims, vec![value; dims.iter().product()])
+    }
+}
+
+impl<T, const N: usize> Grid<T, N> {
+    /// # Panics
+    ///
+    /// Panics if the number of cells doesn't match `dims`.
+    pub fn from_cells(dims: [usize; N], cells: Vec<T>) -> Self {
+        assert_eq!(
+            dims.iter().product::<usize>(),
+            cells.len(),
+            "cells do not fill the grid"
+        );
+        Self { cells, dims }
+    }
+
+    /// The size of every dimension.
+    pub fn dims(&self) -> [usize; N] {
+        self.dims
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn into_cells(self) -> Vec<T> {
+        self.cells
+    }
+
+    /// Position of `point` in [`cells`](Self::cells), if it is inside the grid.
+    pub fn index(&self, point: Point<N>) -> Option<usize> {
+        let mut index = 0;
+        for (coord, dim) in point.0.iter().zip(self.dims).rev() {
+            if *coord >= dim {
+                return None;
+            }
+            index = index * dim + coord;
+        }
+        Some(index)
+    }
+
+    /// Point of the cell at `index` in [`cells`](Self::cells).
+    pub fn point(&self, mut index: usize) -> Option<Point<N>> {
+        if index >= self.cells.len() {
+            return None;
+        }
+        let mut point = [0; N];
+        for (coord, dim) in point.iter_mut().zip(self.dims) {
+            *coord = index % dim;
+            index /= dim;
+        }
+        Some(Point(point))
+    }
+
+    pub fn get(&self, point: Point<N>) -> Option<&T> {
+        self.cells.get(self.index(point)?)
+    }
+
+    pub fn get_mut(&mut self, point: Point<N>) -> Option<&mut T> {
+        let index = self.index(point)?;
+        self.cells.get_mut(index)
+    }
+
+    /// The neighbours of `point` inside the grid, with their cells.
+    pub fn neighbours(
+        &self,
+        point: Point<N>,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (Point<N>, &T)> {
+        point
+            .neighbours(connectivity)
+            .filter_map(|point| Some((point, self.get(point)?)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point<N>, &T)> {
+        self.iter_coords().zip(&self.cells)
+    }
+
+    pub fn iter_coords(&self) -> impl Iterator<Item = Point<N>> + use<T, N> {
+        let dims = self.dims;
+        let len = self.cells.len();
+        let mut point = [0; N];
+        (0..len).map(move |i| {
+            let current = Point(point);
+            if i + 1 < len {
+                for (coord, dim) in point.iter_mut().zip(dims) {
+                    *coord += 1;
+                    if *coord < dim {
+                        break;
+                    }
+                    *coord = 0;
+                }
+            }
+            current
+        })
+    }
+
+    pub fn iter_entries(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+}
+
+impl<T, const N: usize> Index<Point<N>> for Grid<T, N> {
+    type Output = T;
+
+    fn index(&self, point: Point<N>) -> &T {
+        match self.get(point) {
+            Some(t) => t,
+            None => panic!("{point:?} is outside of the grid"),
+        }
+    }
+}
+
+impl<T, const N: usize> IndexMut<Point<N>> for Grid<T, N> {
+    fn index_mut(&mut self, point: Point<N>) -> &mut T {
+        match self.get_mut(point) {
+            Some(t) => t,
+            None => panic!("{point:?} is outside of the grid"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(Point::<3>::offsets(Connectivity::Four).len(), 6);
+        assert_eq!(Point::<3>::offsets(Connectivity::Eight).len(), 26);
+        assert_eq!(Point::<4>::offsets(Connectivity::Eight).len(), 80);
+        assert_eq!(
+            Point::<2>::offsets(Connectivity::Four),
+            [[-1, 0], [1, 0], [0, -1], [0, 1]]
+        );
+    }
+
+    #[test]
+    fn test_index() {
+        let grid = Grid::from_cells([2, 3, 4], (0..24).collect());
+        assert_eq!(grid.index(Point([1, 2, 3])), Some(1 + 2 * 2 + 3 * 6));
+        assert_eq!(grid.index(Point([2, 0, 0])), None);
+        assert_eq!(grid.point(23), Some(Point([1, 2, 3])));
+        assert_eq!(grid.point(24), None);
+        assert_eq!(grid[Point([1, 1, 1])], 9);
+        let coords: Vec<_> = grid.iter_coords().collect();
+        assert_eq!(coords.len(), 24);
+        assert!(coords
+            .iter()
+            .enumerate()
+            .all(|(i, point)| grid.point(i) == Some(*point)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let mut grid = Grid::filled([3, 3, 3, 3], 0);
+        let centre = Point([1, 1, 1, 1]);
+        assert_eq!(grid.neighbours(centre, Connectivity::Eight).count(), 80);
+        assert_eq!(grid.neighbours(centre, Connectivity::Four).count(), 8);
+        grid[Point([0, 0, 0, 0])] = 1;
+        let corner = grid.neighbours(Point([0, 0, 0, 1]), Connectivity::Eight);
+        assert_eq!(corner.map(|(_, t)| t).sum::<i32>(), 1);
+        assert_eq!(
+            grid.neighbours(Point([0; 4]), Connectivity::Eight).count(),
+            15
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid: Grid<char, 3> = Grid::parse("ab\ncd\n").unwrap();
+        assert_eq!(grid.dims(), [2, 2, 1]);
+        assert_eq!(grid[Point([1, 1, 0])], 'd');
+        let err = Grid::<char, 3>::parse("ab\nc\n").unwrap_err();
+        assert_eq!(err.line, 2);
+
+        let grid: Grid<char, 3> = Grid::parse("\nab\ncd\n\nef\ngh\n\n\n\nij\nkl\n").unwrap();
+        assert_eq!(grid.dims(), [2, 2, 3]);
+        assert_eq!(grid[Point([0, 1, 1])], 'g');
+        assert_eq!(grid[Point([1, 0, 2])], 'j');
+        let err = Grid::<char, 3>::parse("ab\ncd\n\nef\ng\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 5, column 2: row of width 1, expected width 2"
+        );
+        let err = Grid::<char, 3>::parse("ab\ncd\n\nef\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: invalid layer, its size differs from the first one in 'ef'"
+        );
+    }
+
+    #[test]
+    fn test_parse_4d() {
+        let input = "ab\ncd\n\nef\ngh\n\n\nij\nkl\n\nmn\nop\n\n\nqr\nst\n\nuv\nwx\n";
+        let grid: Grid<char, 4> = Grid::parse(input).unwrap();
+        assert_eq!(grid.dims(), [2, 2, 2, 3]);
+        assert_eq!(grid[Point([1, 0, 1, 1])], 'n');
+        assert_eq!(grid[Point([1, 1, 1, 2])], 'x');
+
+        let err = Grid::<char, 4>::parse("ab\n\ncd\n\n\nef\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 6, column 3: invalid block of layers, it has another size than the first one"
+        );
+        let grid: Grid<char, 2> = Grid::parse("ab\n\ncd\n").unwrap();
+        assert_eq!(grid.dims(), [2, 2]);
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        assert_eq!(Point([1, 5, 2]).manhattan_distance(Point([3, 1, 2])), 6);
+    }
+}
@@ -0,0 +1,503 @@
+use std::ops::{Index, IndexMut};
+
+use common::parse::{self, ParseError, ParseErrorKind};
+
+use crate::{Boundary, Connectivity, Coords, Grid, SignedCoords};
+
+/// A rectangular grid, stored row by row in one `Vec`. This is a two dimensional [`Grid`]
+/// with [`Coords`] and a [`Boundary`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plane<T> {
+    grid: Grid<T, 2>,
+    boundary: Boundary,
+}
+
+impl<T> From<Grid<T, 2>> for Plane<T> {
+    fn from(grid: Grid<T, 2>) -> Self {
+        Self {
+            grid,
+            boundary: Boundary::default(),
+        }
+    }
+}
+
+impl<T> From<Plane<T>> for Grid<T, 2> {
+    fn from(plane: Plane<T>) -> Self {
+        plane.grid
+    }
+}
+
+/// How [`Plane::parse_with`] treats the lines of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Remove whitespace around every line before splitting it into cells.
+    pub trim: bool,
+    pub blank_lines: BlankLines,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            trim: true,
+            blank_lines: BlankLines::Skip,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlankLines {
+    Skip,
+    /// A blank line is a parse error.
+    Reject,
+}
+
+impl<T> Plane<T>
+where
+    T: TryFrom<char>,
+    ParseErrorKind: From<T::Error>,
+{
+    /// Parses with the default [`ParseOptions`]: lines are trimmed and blank lines are
+    /// skipped.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, ParseOptions::default())
+    }
+
+    /// Every row must be as wide as the first one, otherwise the error points at the end
+    /// of the shorter row or at the extra cells of the longer row.
+    pub fn parse_with(input: &str, options: ParseOptions) -> Result<Self, ParseError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+        for (line_no, line) in parse::lines(input) {
+            let row = if options.trim { line.trim() } else { line };
+            if line.trim().is_empty() {
+                match options.blank_lines {
+                    BlankLines::Skip => continue,
+                    BlankLines::Reject => {
+                        let kind = ParseErrorKind::Invalid("blank line in grid");
+                        return Err(ParseError::new(kind, line, row).on_line(line_no));
+                    }
+                }
+            }
+            let offset = row.as_ptr() as usize - line.as_ptr() as usize;
+            let row_start = cells.len();
+            for (i, c) in row.char_indices() {
+                cells.push(parse_char(line, offset + i, c).map_err(|err| err.on_line(line_no))?);
+            }
+            let found = cells.len() - row_start;
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                let kind = ParseErrorKind::RowWidth { expected, found };
+                let text = match row.char_indices().nth(expected) {
+                    Some((i, _)) => &row[i..],
+                    None => &row[row.len()..],
+                };
+                return Err(ParseError::new(kind, line, text).on_line(line_no));
+            }
+            height += 1;
+        }
+        Ok(Grid::from_cells([width.unwrap_or(0), height], cells).into())
+    }
+}
+
+impl<T> Plane<T> {
+    /// Builds a plane from its cells, row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells is not a multiple of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        let height = match width {
+            0 => 0,
+            width => cells.len() / width,
+        };
+        assert_eq!(width * height, cells.len(), "cells do not fill the rows");
+        Grid::from_cells([width, height], cells).into()
+    }
+
+    /// Sets how steps over the edge of the plane are handled.
+    pub fn with_boundary(mut self, boundary: Boundary) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid.dims()[0]
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.dims()[1]
+    }
+
+    pub fn as_grid(&self) -> &Grid<T, 2> {
+        &self.grid
+    }
+
+    /// All cells, row by row.
+    pub fn cells(&self) -> &[T] {
+        self.grid.cells()
+    }
+
+    /// Position of `coords` in [`cells`](Self::cells), if it is inside the plane.
+    pub fn index(&self, coords: Coords) -> Option<usize> {
+        self.grid.index(coords.into())
+    }
+
+    /// Coords of the cell at `index` in [`cells`](Self::cells).
+    pub fn coords(&self, index: usize) -> Option<Coords> {
+        self.grid.point(index).map(Coords::from)
+    }
+
+    pub fn get(&self, coords: Coords) -> Option<&T> {
+        self.grid.get(coords.into())
+    }
+
+    pub fn get_mut(&mut self, coords: Coords) -> Option<&mut T> {
+        self.grid.get_mut(coords.into())
+    }
+
+    /// The neighbours of `coords` with their cells. They wrap around the edges of a
+    /// [`Wrapping`](Boundary::Wrapping) plane, other planes only have the neighbours inside.
+    /// Every neighbour comes once, even if a narrow wrapping plane reaches it in two
+    /// directions.
+    pub fn neighbours(
+        &self,
+        coords: Coords,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (Coords, &T)> {
+        let boundary = self.neighbour_boundary();
+        let mut neighbours = Vec::with_capacity(connectivity.directions().len());
+        for direction in connectivity.directions() {
+            let neighbour = SignedCoords::from(coords).move_into_direction(*direction);
+            if let Some(neighbour) = self.place_with(neighbour, boundary) {
+                if neighbour != coords && !neighbours.contains(&neighbour) {
+                    neighbours.push(neighbour);
+                }
+            }
+        }
+        neighbours
+            .into_iter()
+            .map(|neighbour| (neighbour, &self[neighbour]))
+    }
+
+    /// Clamping a step onto the cell it starts from does not make that cell a neighbour, so
+    /// a [`Clamped`](Boundary::Clamped) plane has the neighbours of a bounded one.
+    pub(crate) fn neighbour_boundary(&self) -> Boundary {
+        match self.boundary {
+            Boundary::Clamped => Boundary::Bounded,
+            boundary => boundary,
+        }
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        let width = self.width();
+        (y < self.height()).then(|| &self.cells()[y * width..(y + 1) * width])
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [T]> {
+        let width = self.width();
+        (y < self.height()).then(|| &mut self.grid.cells_mut()[y * width..(y + 1) * width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height()).filter_map(|y| self.row(y))
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width()).then(|| self.cells()[x..].iter().step_by(self.width()))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coords, &T)> {
+        self.grid.iter().map(|(point, t)| (Coords::from(point), t))
+    }
+
+    pub fn iter_coords(&self) -> impl Iterator<Item = Coords> + use<T> {
+        self.grid.iter_coords().map(Coords::from)
+    }
+
+    pub fn iter_entries(&self) -> impl Iterator<Item = &T> {
+        self.grid.iter_entries()
+    }
+}
+
+impl<T> Index<Coords> for Plane<T> {
+    type Output = T;
+
+    fn index(&self, coords: Coords) -> &T {
+        match self.get(coords) {
+            Some(t) => t,
+            None => panic!("{coords:?} is outside of the plane"),
+        }
+    }
+}
+
+impl<T> IndexMut<Coords> for Plane<T> {
+    fn index_mut(&mut self, coords: Coords) -> &mut T {
+        match self.get_mut(coords) {
+            Some(t) => t,
+            None => panic!("{coords:?} is outside of the plane"),
+        }
+    }
+}
+
+/// A plane is stored as its rows and its boundary, so snapshots diff line by line.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Plane<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(serde::Serialize)]
+        struct Repr<'a, T> {
+            rows: Vec<&'a [T]>,
+            boundary: Boundary,
+        }
+
+        let repr = Repr {
+            rows: self.rows().collect(),
+            boundary: self.boundary,
+        };
+        repr.serialize(serializer)
+    }
+}
+
+/// The rows must all be equally wide, a missing boundary is [`Boundary::Bounded`].
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Plane<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Repr<T> {
+            rows: Vec<Vec<T>>,
+            #[serde(default)]
+            boundary: Boundary,
+        }
+
+        let Repr { rows, boundary } = Repr::deserialize(deserializer)?;
+        let expected = rows.first().map_or(0, Vec::len);
+        let mut cells = Vec::with_capacity(expected * rows.len());
+        for row in rows {
+            if row.len() != expected {
+                let kind = ParseErrorKind::RowWidth {
+                    expected,
+                    found: row.len(),
+                };
+                return Err(serde::de::Error::custom(kind));
+            }
+            cells.extend(row);
+        }
+        Ok(Self::from_cells(expected, cells).with_boundary(boundary))
+    }
+}
+
+fn parse_char<T>(line: &str, index: usize, c: char) -> Result<T, ParseError>
+where
+    T: TryFrom<char>,
+    ParseErrorKind: From<T::Error>,
+{
+    T::try_from(c).map_err(|err| {
+        let text = &line[index..index + c.len_utf8()];
+        ParseError::new(err.into(), line, text)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size() {
+        let input = "abc\n123";
+        let plane: Plane<char> = Plane::parse(input).unwrap();
+        assert_eq!(plane.width(), 3);
+        assert_eq!(plane.height(), 2);
+    }
+
+    #[test]
+    fn test_parse_trims_lines() {
+        let input = "\n  ab  \n\n  12\n";
+        let plane: Plane<char> = Plane::parse(input).unwrap();
+        assert_eq!((plane.width(), plane.height()), (2, 2));
+        assert_eq!(plane.get(Coords::new(0, 1)), Some(&'1'));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Plane::<Digit>::parse("12\n  3x\n").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::UnexpectedChar);
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "x"));
+    }
+
+    #[test]
+    fn test_ragged() {
+        let err = Plane::<char>::parse("abc\nde\n").unwrap_err();
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::RowWidth {
+                expected: 3,
+                found: 2
+            }
+        );
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, ""));
+
+        let err = Plane::<char>::parse("abc\n\n  defgh").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 6: row of width 5, expected width 3 in 'gh'"
+        );
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let options = ParseOptions {
+            trim: false,
+            ..ParseOptions::default()
+        };
+        let err = Plane::<char>::parse_with("ab\nab \n", options).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, " "));
+        let plane = Plane::<char>::parse_with(" a\n\nb \n", options).unwrap();
+        assert_eq!(plane.row(1), Some(&['b', ' '][..]));
+
+        let options = ParseOptions {
+            blank_lines: BlankLines::Reject,
+            ..ParseOptions::default()
+        };
+        let err = Plane::<char>::parse_with("ab\n  \nab\n", options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: invalid blank line in grid"
+        );
+    }
+
+    #[test]
+    fn test_index() {
+        let mut plane: Plane<char> = Plane::parse("abc\n123").unwrap();
+        assert_eq!(plane.index(Coords::new(1, 1)), Some(4));
+        assert_eq!(plane.index(Coords::new(3, 0)), None);
+        assert_eq!(plane.coords(5), Some(Coords::new(2, 1)));
+        assert_eq!(plane.coords(6), None);
+        plane[Coords::new(0, 1)] = 'x';
+        assert_eq!(plane[Coords::new(0, 1)], 'x');
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let plane: Plane<char> = Plane::parse("abc\n123").unwrap();
+        assert_eq!(plane.row(1), Some(&['1', '2', '3'][..]));
+        assert_eq!(plane.row(2), None);
+        let rows: Vec<_> = plane.rows().collect();
+        assert_eq!(rows, [&['a', 'b', 'c'][..], &['1', '2', '3'][..]]);
+        let column: Vec<_> = plane.column(1).unwrap().collect();
+        assert_eq!(column, [&'b', &'2']);
+        assert!(plane.column(3).is_none());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let plane: Plane<char> = Plane::parse("abc\n123").unwrap();
+        let neighbours: Vec<_> = plane
+            .neighbours(Coords::new(2, 1), Connectivity::Four)
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(neighbours, ['c', '2']);
+        let neighbours: Vec<_> = plane
+            .neighbours(Coords::new(1, 0), Connectivity::Eight)
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(neighbours, ['c', '3', '2', '1', 'a']);
+    }
+
+    #[test]
+    fn test_from_cells() {
+        let plane = Plane::from_cells(2, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!((plane.width(), plane.height()), (2, 3));
+        assert_eq!(plane[Coords::new(1, 2)], 6);
+        let empty = Plane::<u8>::from_cells(0, Vec::new());
+        assert_eq!(empty.iter_coords().count(), 0);
+    }
+
+    #[test]
+    fn test_iter() {
+        let input = "ab\n12";
+        let plane: Plane<char> = Plane::parse(input).unwrap();
+        let entries: Vec<_> = plane.iter().collect();
+        assert_eq!(entries[1], (Coords::new(1, 0), &'b'));
+        assert_eq!(entries[2], (Coords::new(0, 1), &'1'));
+    }
+
+    #[test]
+    fn test_iter_coords() {
+        let input = "ab\n12";
+        let expected: Vec<Coords> =
+            vec![(0, 0).into(), (1, 0).into(), (0, 1).into(), (1, 1).into()];
+        let plane: Plane<char> = Plane::parse(input).unwrap();
+        let coords: Vec<_> = plane.iter_coords().collect();
+        assert_eq!(coords, expected);
+    }
+
+    #[test]
+    fn test_iter_entries() {
+        let input = "ab\n12";
+        let expected: Vec<&char> = vec![&'a', &'b', &'1', &'2'];
+        let plane: Plane<char> = Plane::parse(input).unwrap();
+        let chars: Vec<_> = plane.iter_entries().collect();
+        assert_eq!(chars, expected);
+    }
+
+    #[test]
+    fn test_get_miss() {
+        let input = "ab\n12";
+        let plane: Plane<char> = Plane::parse(input).unwrap();
+        let coords_x = Coords::new(2, 0);
+        let coords_y = Coords::new(0, 2);
+        assert!(plane.get(coords_x).is_none());
+        assert!(plane.get(coords_y).is_none());
+    }
+
+    #[test]
+    fn test_get_hit() {
+        let input = "ab\n12";
+        let plane: Plane<char> = Plane::parse(input).unwrap();
+        let coords_x = Coords::new(1, 0);
+        let coords_y = Coords::new(0, 1);
+        assert_eq!(plane.get(coords_x).unwrap(), &'b');
+        assert_eq!(plane.get(coords_y).unwrap(), &'1');
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let plane = Plane::<char>::parse("ab\n12")
+            .unwrap()
+            .with_boundary(Boundary::Wrapping);
+        let json = serde_json::to_string(&plane).unwrap();
+        assert_eq!(
+            json,
+            r#"{"rows":[["a","b"],["1","2"]],"boundary":"Wrapping"}"#
+        );
+        assert_eq!(serde_json::from_str::<Plane<char>>(&json).unwrap(), plane);
+
+        let plane: Plane<u8> = serde_json::from_str(r#"{"rows":[]}"#).unwrap();
+        assert_eq!((plane.width(), plane.height()), (0, 0));
+        assert_eq!(plane.boundary(), Boundary::Bounded);
+
+        let err = serde_json::from_str::<Plane<u8>>(r#"{"rows":[[1,2],[3]]}"#).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("row of width 1, expected width 2"));
+    }
+
+    #[derive(Debug)]
+    struct Digit;
+
+    impl TryFrom<char> for Digit {
+        type Error = ParseErrorKind;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            c.is_ascii_digit()
+                .then_some(Digit)
+                .ok_or(ParseErrorKind::UnexpectedChar)
+        }
+    }
+}
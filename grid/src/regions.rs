@@ -0,0 +1,199 @@
+use std::collections::VecDeque;
+
+use crate::{Bounds, Connectivity, Coords, Direction, Plane, SignedCoords};
+
+/// Label of a connected component, the index of its [`Region`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ComponentId(pub usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Region {
+    /// Number of cells.
+    pub area: usize,
+    /// Number of cell edges bordering another region or the outside of the plane.
+    pub perimeter: usize,
+    pub bounds: Bounds,
+}
+
+/// Result of [`Plane::components`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    pub labels: Plane<ComponentId>,
+    /// Indexed by [`ComponentId`].
+    pub regions: Vec<Region>,
+}
+
+impl Components {
+    pub fn region(&self, id: ComponentId) -> &Region {
+        &self.regions[id.0]
+    }
+}
+
+impl<T> Plane<T> {
+    /// All cells connected to `seed` through cells that are `inside`, starting with `seed`.
+    /// Empty if `seed` is outside of the plane or not `inside` itself.
+    pub fn flood_fill(
+        &self,
+        seed: Coords,
+        connectivity: Connectivity,
+        mut inside: impl FnMut(Coords, &T) -> bool,
+    ) -> Vec<Coords> {
+        let Some(t) = self.get(seed) else {
+            return Vec::new();
+        };
+        if !inside(seed, t) {
+            return Vec::new();
+        }
+        let mut seen = vec![false; self.cells().len()];
+        self.fill(seed, connectivity, &mut seen, |_, to, t| inside(to, t))
+    }
+
+    /// Labels the connected components, where neighbours are in the same component if
+    /// `same` holds for their cells.
+    pub fn components(
+        &self,
+        connectivity: Connectivity,
+        mut same: impl FnMut(&T, &T) -> bool,
+    ) -> Components {
+        let mut seen = vec![false; self.cells().len()];
+        let mut labels = vec![ComponentId(0); self.cells().len()];
+        let mut regions = Vec::new();
+        for seed in self.iter_coords() {
+            if seen[self.index(seed).unwrap()] {
+                continue;
+            }
+            let id = ComponentId(regions.len());
+            let region = self.fill(seed, connectivity, &mut seen, |from, _, to| {
+                same(&self[from], to)
+            });
+            let mut bounds = Bounds {
+                min: seed,
+                max: seed,
+            };
+            for coords in &region {
+                labels[self.index(*coords).unwrap()] = id;
+                bounds.extend(*coords);
+            }
+            regions.push(Region {
+                area: region.len(),
+                perimeter: 0,
+                bounds,
+            });
+        }
+
+        let labels = Plane::from_cells(self.width(), labels).with_boundary(self.boundary());
+        let boundary = self.neighbour_boundary();
+        for (coords, id) in labels.iter() {
+            regions[id.0].perimeter += Direction::CARDINAL
+                .iter()
+                .filter(|direction| {
+                    let neighbour = SignedCoords::from(coords).move_into_direction(**direction);
+                    let neighbour = labels.place_with(neighbour, boundary);
+                    neighbour.map(|neighbour| &labels[neighbour]) != Some(id)
+                })
+                .count();
+        }
+        Components { labels, regions }
+    }
+
+    /// Breadth first fill from `seed`, marking the filled cells in `seen`.
+    fn fill(
+        &self,
+        seed: Coords,
+        connectivity: Connectivity,
+        seen: &mut [bool],
+        mut connected: impl FnMut(Coords, Coords, &T) -> bool,
+    ) -> Vec<Coords> {
+        seen[self.index(seed).unwrap()] = true;
+        let mut filled = vec![seed];
+        let mut queue = VecDeque::from([seed]);
+        while let Some(coords) = queue.pop_front() {
+            for (next, t) in self.neighbours(coords, connectivity) {
+                let index = self.index(next).unwrap();
+                if !seen[index] && connected(coords, next, t) {
+                    seen[index] = true;
+                    filled.push(next);
+                    queue.push_back(next);
+                }
+            }
+        }
+        filled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Boundary;
+
+    const GARDEN: &str = "\
+        AAAA\n\
+        BBCD\n\
+        BBCC\n\
+        EEEC\n\
+    ";
+
+    #[test]
+    fn test_flood_fill() {
+        let plane: Plane<char> = Plane::parse("..#\n.#.\n#..\n").unwrap();
+        let free = |_, c: &char| *c == '.';
+        let filled = plane.flood_fill(Coords::new(0, 0), Connectivity::Four, free);
+        assert_eq!(filled.len(), 3);
+        assert_eq!(filled[0], Coords::new(0, 0));
+        let filled = plane.flood_fill(Coords::new(0, 0), Connectivity::Eight, free);
+        assert_eq!(filled.len(), 6);
+        assert!(plane
+            .flood_fill(Coords::new(2, 0), Connectivity::Four, free)
+            .is_empty());
+        assert!(plane
+            .flood_fill(Coords::new(3, 0), Connectivity::Four, free)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_components() {
+        let plane: Plane<char> = Plane::parse(GARDEN).unwrap();
+        let components = plane.components(Connectivity::Four, |a, b| a == b);
+        let summary: Vec<_> = components
+            .regions
+            .iter()
+            .map(|region| (region.area, region.perimeter))
+            .collect();
+        assert_eq!(summary, [(4, 10), (4, 8), (4, 10), (1, 4), (3, 8)]);
+
+        let c = components.labels[Coords::new(2, 1)];
+        assert_eq!(c, components.labels[Coords::new(3, 3)]);
+        assert_eq!(
+            components.region(c).bounds,
+            Bounds {
+                min: Coords::new(2, 1),
+                max: Coords::new(3, 3)
+            }
+        );
+        assert_eq!(
+            (
+                components.region(c).bounds.width(),
+                components.region(c).bounds.height()
+            ),
+            (2, 3)
+        );
+    }
+
+    #[test]
+    fn test_components_wrapping() {
+        let plane: Plane<char> = Plane::parse("aba\nbbb\naba").unwrap();
+        let summary = |plane: &Plane<char>| -> Vec<_> {
+            let components = plane.components(Connectivity::Four, |a, b| a == b);
+            components
+                .regions
+                .iter()
+                .map(|region| (region.area, region.perimeter))
+                .collect()
+        };
+        assert_eq!(summary(&plane), [(1, 4), (5, 12), (1, 4), (1, 4), (1, 4)]);
+        let plane = plane.with_boundary(Boundary::Wrapping);
+        assert_eq!(summary(&plane), [(4, 8), (5, 8)]);
+        let plane = plane.with_boundary(Boundary::Clamped);
+        assert_eq!(summary(&plane)[1], (5, 12));
+    }
+}
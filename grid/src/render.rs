@@ -0,0 +1,72 @@
+use std::fmt;
+
+use crate::{Coords, Plane};
+
+/// Cells that can be drawn as one character, the inverse of parsing them with
+/// `TryFrom<char>`.
+pub trait ToChar {
+    fn to_char(&self) -> char;
+}
+
+impl ToChar for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+impl<T> Plane<T> {
+    /// Draws every cell with `cell`, one line per row.
+    pub fn render(&self, mut cell: impl FnMut(Coords, &T) -> char) -> String {
+        let mut out = String::with_capacity((self.width() + 1) * self.height());
+        for (coords, t) in self.iter() {
+            out.push(cell(coords, t));
+            if coords.x + 1 == self.width() {
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+impl<T: ToChar> Plane<T> {
+    /// Like the [`Display`](fmt::Display) output, but cells for which `overlay` returns a
+    /// char are drawn with that char instead, e.g. to highlight a path.
+    pub fn render_overlay(&self, mut overlay: impl FnMut(Coords, &T) -> Option<char>) -> String {
+        self.render(|coords, t| overlay(coords, t).unwrap_or_else(|| t.to_char()))
+    }
+}
+
+/// Prints the rows as they were parsed, each followed by a newline.
+impl<T: ToChar> fmt::Display for Plane<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for t in row {
+                write!(f, "{}", t.to_char())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let input = "ab.\n.cd\n";
+        let plane: Plane<char> = Plane::parse(input).unwrap();
+        assert_eq!(plane.to_string(), input);
+        assert_eq!(Plane::parse(&plane.to_string()), Ok(plane));
+    }
+
+    #[test]
+    fn test_render_overlay() {
+        let plane: Plane<char> = Plane::parse("ab.\n.cd\n").unwrap();
+        let rendered =
+            plane.render_overlay(|coords, c| (coords.x == coords.y || *c == 'd').then_some('#'));
+        assert_eq!(rendered, "#b.\n.##\n");
+        assert_eq!(plane.render(|_, _| 'x'), "xxx\nxxx\n");
+    }
+}
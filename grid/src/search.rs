@@ -0,0 +1,201 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use crate::{Connectivity, Coords, Plane};
+
+/// A shortest path found by one of the searches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    /// From the start to the goal, both included.
+    pub coords: Vec<Coords>,
+    pub cost: u64,
+}
+
+impl<T> Plane<T> {
+    /// Breadth first search for the path with the fewest steps from `start` to `goal`,
+    /// only stepping onto cells that are `passable`.
+    pub fn bfs(
+        &self,
+        start: Coords,
+        goal: Coords,
+        connectivity: Connectivity,
+        mut passable: impl FnMut(Coords, &T) -> bool,
+    ) -> Option<Path> {
+        let start_index = self.index(start)?;
+        let goal_index = self.index(goal)?;
+        let mut prev = vec![None; self.cells().len()];
+        let mut seen = vec![false; self.cells().len()];
+        seen[start_index] = true;
+        let mut queue = VecDeque::from([start]);
+        while let Some(coords) = queue.pop_front() {
+            if coords == goal {
+                return Some(self.path(&prev, goal_index));
+            }
+            for (next, t) in self.neighbours(coords, connectivity) {
+                let index = self.index(next)?;
+                if !seen[index] && passable(next, t) {
+                    seen[index] = true;
+                    prev[index] = Some(coords);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Dijkstra's algorithm for the cheapest path from `start` to `goal`.
+    ///
+    /// `cost` gives the cost of a step between two neighbours, with the cell stepped onto,
+    /// or `None` if the step is not possible.
+    pub fn dijkstra(
+        &self,
+        start: Coords,
+        goal: Coords,
+        connectivity: Connectivity,
+        cost: impl FnMut(Coords, Coords, &T) -> Option<u64>,
+    ) -> Option<Path> {
+        self.astar(start, goal, connectivity, cost, |_| 0)
+    }
+
+    /// A* search for the cheapest path from `start` to `goal`, with `cost` as in
+    /// [`dijkstra`](Self::dijkstra).
+    ///
+    /// `heuristic` estimates the remaining cost to the goal. The path is only guaranteed
+    /// to be the cheapest if it never overestimates, e.g. [`Coords::manhattan_distance`]
+    /// for steps of cost at least one with [`Connectivity::Four`].
+    pub fn astar(
+        &self,
+        start: Coords,
+        goal: Coords,
+        connectivity: Connectivity,
+        mut cost: impl FnMut(Coords, Coords, &T) -> Option<u64>,
+        mut heuristic: impl FnMut(Coords) -> u64,
+    ) -> Option<Path> {
+        let start_index = self.index(start)?;
+        let goal_index = self.index(goal)?;
+        let mut prev = vec![None; self.cells().len()];
+        let mut best = vec![u64::MAX; self.cells().len()];
+        best[start_index] = 0;
+        let mut queue = BinaryHeap::from([Reverse((heuristic(start), 0, start_index))]);
+        while let Some(Reverse((_, so_far, index))) = queue.pop() {
+            if index == goal_index {
+                let mut path = self.path(&prev, goal_index);
+                path.cost = so_far;
+                return Some(path);
+            }
+            if so_far > best[index] {
+                continue;
+            }
+            let coords = self.coords(index)?;
+            for (next, t) in self.neighbours(coords, connectivity) {
+                let Some(step) = cost(coords, next, t) else {
+                    continue;
+                };
+                let next_index = self.index(next)?;
+                let total = so_far + step;
+                if total < best[next_index] {
+                    best[next_index] = total;
+                    prev[next_index] = Some(coords);
+                    queue.push(Reverse((total + heuristic(next), total, next_index)));
+                }
+            }
+        }
+        None
+    }
+
+    /// Follows `prev` back from the goal, the cost is the number of steps.
+    fn path(&self, prev: &[Option<Coords>], goal_index: usize) -> Path {
+        let mut coords = vec![self.coords(goal_index).unwrap()];
+        let mut index = goal_index;
+        while let Some(before) = prev[index] {
+            coords.push(before);
+            index = self.index(before).unwrap();
+        }
+        coords.reverse();
+        Path {
+            cost: coords.len() as u64 - 1,
+            coords,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "\
+        S.#...\n\
+        .##.#.\n\
+        ....#G\n\
+    ";
+
+    fn maze() -> (Plane<char>, Coords, Coords) {
+        let plane: Plane<char> = Plane::parse(MAZE).unwrap();
+        (plane, Coords::new(0, 0), Coords::new(5, 2))
+    }
+
+    fn assert_connected(path: &Path) {
+        for pair in path.coords.windows(2) {
+            let diff = pair[0].diff(pair[1]);
+            assert_eq!(diff.x.abs() + diff.y.abs(), 1, "{pair:?}");
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (plane, start, goal) = maze();
+        let path = plane
+            .bfs(start, goal, Connectivity::Four, |_, c| *c != '#')
+            .unwrap();
+        assert_eq!(path.cost, 11);
+        assert_eq!(path.coords.first(), Some(&start));
+        assert_eq!(path.coords.last(), Some(&goal));
+        assert_connected(&path);
+
+        let path = plane.bfs(start, goal, Connectivity::Eight, |_, c| *c != '#');
+        assert_eq!(path.unwrap().cost, 7);
+        assert!(plane
+            .bfs(start, goal, Connectivity::Four, |_, c| *c == '.')
+            .is_none());
+        assert_eq!(
+            plane.bfs(start, start, Connectivity::Four, |_, _| false),
+            Some(Path {
+                coords: vec![start],
+                cost: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let plane: Plane<char> = Plane::parse("S~~G\n.#..\n....\n").unwrap();
+        let (start, goal) = (Coords::new(0, 0), Coords::new(3, 0));
+        let cost = |_, _, c: &char| match c {
+            '#' => None,
+            '~' => Some(9),
+            _ => Some(1),
+        };
+        let path = plane
+            .dijkstra(start, goal, Connectivity::Four, cost)
+            .unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.coords.len(), 8);
+        assert_connected(&path);
+
+        let path = plane.bfs(start, goal, Connectivity::Four, |_, c| *c != '#');
+        assert_eq!(path.unwrap().cost, 3);
+    }
+
+    #[test]
+    fn test_astar() {
+        let (plane, start, goal) = maze();
+        let cost = |_, _, c: &char| (*c != '#').then_some(1);
+        let path = plane
+            .astar(start, goal, Connectivity::Four, cost, |coords| {
+                coords.manhattan_distance(goal)
+            })
+            .unwrap();
+        let bfs = plane.bfs(start, goal, Connectivity::Four, |_, c| *c != '#');
+        assert_eq!(Some(path), bfs);
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use crate::{Connectivity, Plane, SignedCoords, ToChar};
+
+/// A grid without bounds that only stores the occupied cells, so it can grow in any
+/// direction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparsePlane<T> {
+    cells: HashMap<SignedCoords, T>,
+}
+
+impl<T> Default for SparsePlane<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> FromIterator<(SignedCoords, T)> for SparsePlane<T> {
+    fn from_iter<I: IntoIterator<Item = (SignedCoords, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> SparsePlane<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Occupies the cells of `plane` for which `cell` returns a value.
+    pub fn from_plane<U>(plane: &Plane<U>, mut cell: impl FnMut(&U) -> Option<T>) -> Self {
+        plane
+            .iter()
+            .filter_map(|(coords, u)| Some((coords.into(), cell(u)?)))
+            .collect()
+    }
+
+    /// Number of occupied cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, coords: SignedCoords) -> Option<&T> {
+        self.cells.get(&coords)
+    }
+
+    pub fn get_mut(&mut self, coords: SignedCoords) -> Option<&mut T> {
+        self.cells.get_mut(&coords)
+    }
+
+    pub fn contains(&self, coords: SignedCoords) -> bool {
+        self.cells.contains_key(&coords)
+    }
+
+    /// Returns the previous value of the cell.
+    pub fn insert(&mut self, coords: SignedCoords, t: T) -> Option<T> {
+        self.cells.insert(coords, t)
+    }
+
+    pub fn remove(&mut self, coords: SignedCoords) -> Option<T> {
+        self.cells.remove(&coords)
+    }
+
+    /// The occupied cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (SignedCoords, &T)> {
+        self.cells.iter().map(|(coords, t)| (*coords, t))
+    }
+
+    /// The occupied neighbours of `coords`.
+    pub fn neighbours(
+        &self,
+        coords: SignedCoords,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (SignedCoords, &T)> {
+        coords
+            .neighbours(connectivity)
+            .filter_map(|coords| Some((coords, self.get(coords)?)))
+    }
+
+    /// The top left and bottom right corners of the occupied cells.
+    pub fn bounds(&self) -> Option<(SignedCoords, SignedCoords)> {
+        let mut coords = self.cells.keys();
+        let first = *coords.next()?;
+        Some(coords.fold((first, first), |(min, max), c| {
+            (
+                SignedCoords::new(min.x.min(c.x), min.y.min(c.y)),
+                SignedCoords::new(max.x.max(c.x), max.y.max(c.y)),
+            )
+        }))
+    }
+}
+
+impl<T: ToChar> SparsePlane<T> {
+    /// Draws the bounds of the occupied cells, with `empty` for the other cells.
+    pub fn render(&self, empty: char) -> String {
+        let Some((min, max)) = self.bounds() else {
+            return String::new();
+        };
+        let mut out = String::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let cell = self.get(SignedCoords::new(x, y));
+                out.push(cell.map(ToChar::to_char).unwrap_or(empty));
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One generation of Conway's game of life.
+    fn life(cells: &SparsePlane<char>) -> SparsePlane<char> {
+        let mut candidates: Vec<_> = cells
+            .iter()
+            .flat_map(|(coords, _)| coords.neighbours(Connectivity::Eight))
+            .collect();
+        candidates.extend(cells.iter().map(|(coords, _)| coords));
+        candidates
+            .into_iter()
+            .filter(|coords| {
+                let alive = cells.neighbours(*coords, Connectivity::Eight).count();
+                alive == 3 || (alive == 2 && cells.contains(*coords))
+            })
+            .map(|coords| (coords, '#'))
+            .collect()
+    }
+
+    #[test]
+    fn test_glider_leaves_the_origin() {
+        let plane: Plane<char> = Plane::parse("##.\n#.#\n#..").unwrap();
+        let mut cells = SparsePlane::from_plane(&plane, |c| (*c == '#').then_some('#'));
+        assert_eq!(cells.len(), 5);
+        for _ in 0..4 {
+            cells = life(&cells);
+        }
+        let (min, max) = cells.bounds().unwrap();
+        assert_eq!((min, max), ((-1, -1).into(), (1, 1).into()));
+        assert_eq!(cells.render('.'), plane.to_string());
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let mut cells = SparsePlane::new();
+        assert_eq!(cells.bounds(), None);
+        assert_eq!(cells.render('.'), "");
+        cells.insert((-2, 3).into(), 'a');
+        assert_eq!(cells.insert((1, -1).into(), 'b'), None);
+        assert_eq!(cells.insert((1, -1).into(), 'c'), Some('b'));
+        assert_eq!(cells.render('.'), "...c\n....\n....\n....\na...\n");
+        assert_eq!(cells.remove((1, -1).into()), Some('c'));
+        assert_eq!(cells.len(), 1);
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{Bounds, Coords, Plane};
+
+impl<T: Clone> Plane<T> {
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height(), self.width(), |coords| {
+            Coords::new(coords.y, coords.x)
+        })
+    }
+
+    /// Rotates by 90° clockwise.
+    pub fn rotate_right(&self) -> Self {
+        let height = self.height();
+        self.remap(height, self.width(), |coords| {
+            Coords::new(coords.y, height - 1 - coords.x)
+        })
+    }
+
+    /// Rotates by 90° counter-clockwise.
+    pub fn rotate_left(&self) -> Self {
+        let width = self.width();
+        self.remap(self.height(), width, |coords| {
+            Coords::new(width - 1 - coords.y, coords.x)
+        })
+    }
+
+    /// Mirrors left and right.
+    pub fn flip_horizontal(&self) -> Self {
+        let width = self.width();
+        self.remap(width, self.height(), |coords| {
+            Coords::new(width - 1 - coords.x, coords.y)
+        })
+    }
+
+    /// Mirrors top and bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let height = self.height();
+        self.remap(self.width(), height, |coords| {
+            Coords::new(coords.x, height - 1 - coords.y)
+        })
+    }
+
+    /// The plane in all four rotations, starting with itself.
+    pub fn rotations(&self) -> [Self; 4] {
+        let right = self.rotate_right();
+        let half = right.rotate_right();
+        [self.clone(), right, half, self.rotate_left()]
+    }
+
+    /// Builds a plane of the given size, taking each cell from `source(coords)` in here.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(Coords) -> Coords) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Coords::new(x, y)))
+            .map(|coords| self[source(coords)].clone())
+            .collect();
+        Self::from_cells(width, cells).with_boundary(self.boundary())
+    }
+}
+
+impl<T> Plane<T> {
+    /// Borrows the cells inside `bounds`, `None` if they reach outside of the plane.
+    pub fn view(&self, bounds: Bounds) -> Option<View<'_, T>> {
+        (bounds.min.x <= bounds.max.x
+            && bounds.min.y <= bounds.max.y
+            && self.index(bounds.max).is_some())
+        .then_some(View {
+            plane: self,
+            bounds,
+        })
+    }
+
+    /// All views of the given size, row by row.
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = View<'_, T>> {
+        let (xs, ys) = match (width, height) {
+            (0, _) | (_, 0) => (0, 0),
+            _ => (
+                (self.width() + 1).saturating_sub(width),
+                (self.height() + 1).saturating_sub(height),
+            ),
+        };
+        (0..ys)
+            .flat_map(move |y| (0..xs).map(move |x| Coords::new(x, y)))
+            .map(move |min| View {
+                plane: self,
+                bounds: Bounds {
+                    min,
+                    max: Coords::new(min.x + width - 1, min.y + height - 1),
+                },
+            })
+    }
+}
+
+/// A borrowed rectangular part of a [`Plane`], with coords relative to its top left
+/// corner.
+#[derive(Debug)]
+pub struct View<'a, T> {
+    plane: &'a Plane<T>,
+    bounds: Bounds,
+}
+
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        self.bounds.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds.height()
+    }
+
+    /// Position of the view in the plane.
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    /// Coords in the plane of `coords` in the view.
+    pub fn to_plane_coords(&self, coords: Coords) -> Option<Coords> {
+        (coords.x < self.width() && coords.y < self.height())
+            .then(|| Coords::new(self.bounds.min.x + coords.x, self.bounds.min.y + coords.y))
+    }
+
+    pub fn get(&self, coords: Coords) -> Option<&'a T> {
+        self.plane.get(self.to_plane_coords(coords)?)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&'a [T]> {
+        let row = self
+            .plane
+            .row(self.bounds.min.y + y)
+            .filter(|_| y < self.height())?;
+        Some(&row[self.bounds.min.x..=self.bounds.max.x])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + use<'a, T> {
+        let view = *self;
+        (0..self.height()).filter_map(move |y| view.row(y))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coords, &'a T)> + use<'a, T> {
+        self.rows().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(x, t)| (Coords::new(x, y), t))
+        })
+    }
+
+    pub fn to_plane(&self) -> Plane<T>
+    where
+        T: Clone,
+    {
+        let cells = self.rows().flat_map(|row| row.iter().cloned()).collect();
+        Plane::from_cells(self.width(), cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plane(input: &str) -> Plane<char> {
+        Plane::parse(input).unwrap()
+    }
+
+    #[test]
+    fn test_transpose() {
+        assert_eq!(plane("abc\n123").transpose(), plane("a1\nb2\nc3"));
+    }
+
+    #[test]
+    fn test_rotate() {
+        let p = plane("abc\n123");
+        assert_eq!(p.rotate_right(), plane("1a\n2b\n3c"));
+        assert_eq!(p.rotate_left(), plane("c3\nb2\na1"));
+        assert_eq!(p.rotate_right().rotate_left(), p);
+        let rotations = p.rotations();
+        assert_eq!(rotations[2], plane("321\ncba"));
+        assert_eq!(rotations[3].rotate_right(), p);
+    }
+
+    #[test]
+    fn test_flip() {
+        let p = plane("abc\n123");
+        assert_eq!(p.flip_horizontal(), plane("cba\n321"));
+        assert_eq!(p.flip_vertical(), plane("123\nabc"));
+        assert_eq!(p.flip_horizontal().flip_vertical(), p.rotations()[2]);
+    }
+
+    #[test]
+    fn test_view() {
+        let p = plane("abcd\n1234\nwxyz");
+        let bounds = Bounds {
+            min: Coords::new(1, 1),
+            max: Coords::new(2, 2),
+        };
+        let view = p.view(bounds).unwrap();
+        assert_eq!((view.width(), view.height()), (2, 2));
+        assert_eq!(view.get(Coords::new(1, 0)), Some(&'3'));
+        assert_eq!(view.get(Coords::new(2, 0)), None);
+        assert_eq!(view.row(1), Some(&['x', 'y'][..]));
+        assert_eq!(view.row(2), None);
+        assert_eq!(view.to_plane(), plane("23\nxy"));
+        assert_eq!(view.iter().nth(2), Some((Coords::new(0, 1), &'x')));
+
+        let outside = Bounds {
+            min: Coords::new(3, 0),
+            max: Coords::new(4, 0),
+        };
+        assert!(p.view(outside).is_none());
+    }
+
+    #[test]
+    fn test_windows() {
+        let p = plane("abcd\n1234\nwxyz");
+        let windows: Vec<_> = p.windows(3, 2).map(|view| view.to_plane()).collect();
+        assert_eq!(
+            windows,
+            [
+                plane("abc\n123"),
+                plane("bcd\n234"),
+                plane("123\nwxy"),
+                plane("234\nxyz")
+            ]
+        );
+        assert_eq!(p.windows(5, 1).count(), 0);
+        assert_eq!(p.windows(0, 1).count(), 0);
+    }
+}